@@ -0,0 +1,235 @@
+use std::hint::black_box;
+
+use aoc_2023_day1::{
+    find_first_ascii_digit, generate_input, get_coord, get_coord_for, parse_lines, Part,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+/// The number of synthetic lines fed to each benchmark iteration.
+const LINE_COUNT: usize = 1000;
+
+/// The seed of the synthetic input, fixed so that every run benchmarks the same lines.
+const SEED: u64 = 0x2023_0001;
+
+/// The function `synthetic_lines` builds `count` reproducible lines mixing numeric and spelled digits.
+fn synthetic_lines(count: usize) -> Vec<String> {
+    parse_lines(&generate_input(count, SEED))
+}
+
+fn bench_get_coord(c: &mut Criterion) {
+    let lines = synthetic_lines(LINE_COUNT);
+
+    let mut group = c.benchmark_group("get_coord");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("mixed", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| get_coord(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+
+    let numerals: Vec<String> = lines
+        .iter()
+        .map(|line| line.chars().filter(char::is_ascii_digit).collect())
+        .collect();
+    group.bench_function("numeral_heavy", |b| {
+        b.iter(|| {
+            numerals
+                .iter()
+                .filter_map(|line| get_coord(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+fn bench_parts(c: &mut Criterion) {
+    let lines = synthetic_lines(LINE_COUNT);
+
+    for (name, part) in [("part_one", Part::One), ("part_two", Part::Two)] {
+        let mut group = c.benchmark_group(name);
+        group.throughput(Throughput::Elements(lines.len() as u64));
+        group.bench_function("mixed", |b| {
+            b.iter(|| {
+                lines
+                    .iter()
+                    .filter_map(|line| get_coord_for(black_box(line), part))
+                    .sum::<u32>()
+            })
+        });
+        group.finish();
+    }
+}
+
+/// The parse path `get_coord` had before it returned digits as `u8`s: every found digit is turned into a `String`,
+/// both are concatenated and the result is parsed back into a number, allocating several times per line. It is kept
+/// here only as the baseline `bench_allocation` measures the allocation-free path against.
+mod string_concat {
+    use std::ops::Add;
+
+    const DIGITS: &[&str] = &[
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    fn find_first_alpha_digit(str: &str) -> Option<(String, usize)> {
+        DIGITS
+            .iter()
+            .filter_map(|&substring| {
+                str.find(substring)
+                    .map(|index| (substring.to_string(), index))
+            })
+            .min_by_key(|&(_, index)| index)
+    }
+
+    fn find_last_alpha_digit(str: &str) -> Option<(String, usize)> {
+        let mut last_index = None;
+        let mut last_substring = None;
+
+        for &substring in DIGITS {
+            if let Some(index) = str.rfind(substring) {
+                if last_index.is_none_or(|last| index > last) {
+                    last_index = Some(index);
+                    last_substring = Some((substring.to_string(), index));
+                }
+            }
+        }
+
+        last_substring
+    }
+
+    fn alpha_to_numeric(alpha_digit: &str) -> Option<String> {
+        DIGITS
+            .iter()
+            .position(|&digit| digit == alpha_digit)
+            .map(|result| (result as u8 + 1).to_string())
+    }
+
+    fn find_first_numeric_digit(str: &str) -> Option<(String, usize)> {
+        str.chars()
+            .enumerate()
+            .find(|(_, c)| c.is_ascii_digit())
+            .map(|(idx, char)| (char.to_string(), idx))
+    }
+
+    fn find_last_numeric_digit(str: &str) -> Option<(String, usize)> {
+        str.chars()
+            .collect::<Vec<char>>()
+            .into_iter()
+            .enumerate()
+            .rev()
+            .find(|(_, c)| c.is_ascii_digit())
+            .map(|(idx, char)| (char.to_string(), idx))
+    }
+
+    fn get_first_digit(line: &str) -> Option<String> {
+        match (find_first_numeric_digit(line), find_first_alpha_digit(line)) {
+            (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_idx))) => {
+                if numeric_idx < alpha_idx {
+                    Some(numeric_digit)
+                } else {
+                    alpha_to_numeric(&alpha_digit)
+                }
+            }
+            (Some((numeric_digit, _)), None) => Some(numeric_digit),
+            (None, Some((alpha_digit, _))) => alpha_to_numeric(&alpha_digit),
+            _ => None,
+        }
+    }
+
+    fn get_last_digit(line: &str) -> Option<String> {
+        match (find_last_numeric_digit(line), find_last_alpha_digit(line)) {
+            (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_idx))) => {
+                if numeric_idx > alpha_idx {
+                    Some(numeric_digit)
+                } else {
+                    alpha_to_numeric(&alpha_digit)
+                }
+            }
+            (Some((numeric_digit, _)), None) => Some(numeric_digit),
+            (None, Some((alpha_digit, _))) => alpha_to_numeric(&alpha_digit),
+            _ => None,
+        }
+    }
+
+    pub fn get_coord(line: &str) -> Option<u32> {
+        let coord = String::new()
+            .add(&get_first_digit(line)?)
+            .add(&get_last_digit(line)?);
+
+        coord.parse::<u32>().ok()
+    }
+}
+
+/// The function `bench_allocation` compares the allocation-free `get_coord` against the `String`-concatenating path it
+/// replaced, after checking that both agree on the synthetic lines.
+fn bench_allocation(c: &mut Criterion) {
+    let lines = synthetic_lines(LINE_COUNT);
+    assert_eq!(
+        lines.iter().filter_map(|line| get_coord(line)).sum::<u32>(),
+        lines
+            .iter()
+            .filter_map(|line| string_concat::get_coord(line))
+            .sum::<u32>()
+    );
+
+    let mut group = c.benchmark_group("allocation");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("arithmetic", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| get_coord(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("string_concat", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| string_concat::get_coord(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+/// The function `bench_numeric_digit` compares the byte-level `find_first_ascii_digit` against decoding each line
+/// character by character.
+fn bench_numeric_digit(c: &mut Criterion) {
+    let lines = synthetic_lines(LINE_COUNT);
+
+    let mut group = c.benchmark_group("first_numeric_digit");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("bytes", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| find_first_ascii_digit(black_box(line).as_bytes()))
+                .count()
+        })
+    });
+    group.bench_function("chars", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| {
+                    black_box(line)
+                        .char_indices()
+                        .find_map(|(idx, char)| char.to_digit(10).map(|value| (value, idx)))
+                })
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_get_coord,
+    bench_parts,
+    bench_allocation,
+    bench_numeric_digit
+);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+#![no_main]
+
+use aoc_2023_day1::{get_coord, get_coord_with, solve_str, DigitMatcher};
+use libfuzzer_sys::fuzz_target;
+
+// Feeds arbitrary bytes, decoded lossily as UTF-8, through the line parser: it must never panic, and every coordinate
+// it produces must be a two-digit number. The matcher-based parser must agree with the built-in one, and with the
+// `test-util` feature its reverse scan is checked against the `rfind` reference implementation.
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    let matcher = DigitMatcher::english();
+
+    for line in input.lines() {
+        let coord = get_coord(line);
+        if let Some(coord) = coord {
+            assert!(coord <= 99, "coordinate {} out of range for {:?}", coord, line);
+        }
+        assert_eq!(coord, get_coord_with(line, &matcher), "parsers disagree on {:?}", line);
+    }
+
+    solve_str(&input);
+});
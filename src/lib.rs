@@ -0,0 +1,2098 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "aho-corasick")]
+pub mod matcher;
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, BufRead},
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The macro `debug` forwards its message to `log::debug!` when the `log` feature is enabled. Without it the message is
+/// still type-checked, so that the arguments count as used, but never formatted.
+#[cfg(feature = "log")]
+macro_rules! debug {
+    ($($arg:tt)*) => { log::debug!($($arg)*) };
+}
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)*) => { { let _ = format_args!($($arg)*); } };
+}
+
+/// The two parts of the puzzle: Part 1 only reads numeric digits, while Part 2 also reads digits spelled out as words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Part {
+    One,
+    #[default]
+    Two,
+}
+
+/// A single decimal digit, `0` through `9`, found in a line either as a numeral or as a spelled-out word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digit(u8);
+
+impl Digit {
+    /// The function `new` creates a `Digit` from its numeric value.
+    ///
+    /// Arguments:
+    ///
+    /// * `value`: The `value` parameter is a `u8` that represents the numeric value of the digit.
+    ///
+    /// Returns:
+    ///
+    /// The function `new` returns an `Option<Digit>`, which is `None` when `value` is greater than nine.
+    pub fn new(value: u8) -> Option<Digit> {
+        (value <= 9).then_some(Digit(value))
+    }
+
+    /// The function `value` returns the numeric value of the digit.
+    pub fn value(self) -> u8 {
+        self.0
+    }
+
+    /// The function `to_char` renders the digit as its ASCII character, which is handy for debug output.
+    pub fn to_char(self) -> char {
+        char::from(b'0' + self.0)
+    }
+}
+
+impl fmt::Display for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// Where a digit was read from: a numeral such as `7`, or a spelled-out word such as `"seven"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    Numeral,
+    Word,
+}
+
+/// The reasons a line can fail to produce a coordinate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordError {
+    /// The line contains neither a numeric nor a spelled digit.
+    NoDigits,
+}
+
+impl fmt::Display for CoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordError::NoDigits => write!(f, "line contains no digits"),
+        }
+    }
+}
+
+impl core::error::Error for CoordError {}
+
+/// The problems `DigitMatcher::validate` can find in a word list.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatcherError {
+    /// A word is the empty string, which would match everywhere.
+    EmptyWord,
+    /// The same word is listed more than once.
+    DuplicateWord(String),
+    /// A digit word is a prefix of another digit word, so both match where the longer one appears.
+    PrefixOverlap { prefix: String, word: String },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for MatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatcherError::EmptyWord => write!(f, "the word list contains an empty word"),
+            MatcherError::DuplicateWord(word) => {
+                write!(f, "the word '{}' is listed more than once", word)
+            }
+            MatcherError::PrefixOverlap { prefix, word } => {
+                write!(
+                    f,
+                    "the word '{}' is a prefix of the word '{}'",
+                    prefix, word
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for MatcherError {}
+
+/// The `const DIGITS: &[&str] = &[...]` line is defining a constant array of string slices. Each string slice represents a
+/// digit from zero to nine, stored at the index equal to its numeric value. This array is used in various functions to
+/// match and convert alphabetic digits to their corresponding numeric values.
+const DIGITS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The `WORD_INITIALS` table flags, for every byte value, whether some word of `DIGITS` starts with it. It is built at
+/// compile time from `DIGITS`, so the two can't drift apart.
+const WORD_INITIALS: [bool; 256] = {
+    let mut initials = [false; 256];
+    let mut idx = 0;
+    while idx < DIGITS.len() {
+        initials[DIGITS[idx].as_bytes()[0] as usize] = true;
+        idx += 1;
+    }
+    initials
+};
+
+/// The function `may_spell_digit` tells whether a spelled digit from `DIGITS` could start anywhere in the line, by
+/// looking for one of their initial letters. When it returns `false`, only numerals need to be searched for.
+fn may_spell_digit(line: &str) -> bool {
+    line.bytes().any(|byte| WORD_INITIALS[usize::from(byte)])
+}
+
+/// A `DigitMatcher` holds the spelled-out words recognised as digits, each paired with its numeric value, so that the
+/// word list can be swapped at runtime (for example to solve a localized variant of the puzzle). Matching is
+/// case-sensitive unless enabled otherwise with `case_insensitive`, every matching word counts unless
+/// `longest_word_wins` is enabled, words match anywhere as substrings unless `whole_words` is enabled, and a numeral
+/// beats a word starting at the same index unless `prefer_words` is enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitMatcher {
+    words: Vec<(String, u8)>,
+    lowercase_words: Vec<(String, u8)>,
+    case_insensitive: bool,
+    longest_word_wins: bool,
+    whole_words: bool,
+    prefer_words: bool,
+}
+
+#[cfg(feature = "std")]
+impl DigitMatcher {
+    /// The function `new` builds a matcher from a custom list of words.
+    ///
+    /// Arguments:
+    ///
+    /// * `words`: The `words` parameter is an iterable of `(word, value)` pairs. Words mapped to a value above nine
+    ///   never produce a digit, but can still hide shorter words when `longest_word_wins` is enabled.
+    ///
+    /// Returns:
+    ///
+    /// The function `new` returns a `DigitMatcher` recognising exactly the given words.
+    pub fn new<I, S>(words: I) -> DigitMatcher
+    where
+        I: IntoIterator<Item = (S, u8)>,
+        S: Into<String>,
+    {
+        DigitMatcher {
+            words: words
+                .into_iter()
+                .map(|(word, value)| (word.into(), value))
+                .collect(),
+            lowercase_words: Vec::new(),
+            case_insensitive: false,
+            longest_word_wins: false,
+            whole_words: false,
+            prefer_words: false,
+        }
+    }
+
+    /// The function `case_insensitive` toggles ASCII case-insensitive matching. When enabled, a lowercased copy of the
+    /// words is made once here and each line is lowercased once before scanning, so `"OnE"` matches `"one"`. The words
+    /// themselves are kept as given, so disabling the option again matches them with their original case.
+    ///
+    /// Arguments:
+    ///
+    /// * `case_insensitive`: The `case_insensitive` parameter is a `bool` enabling or disabling the option.
+    ///
+    /// Returns:
+    ///
+    /// The function `case_insensitive` returns the updated `DigitMatcher`.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> DigitMatcher {
+        self.lowercase_words = if case_insensitive {
+            self.words
+                .iter()
+                .map(|(word, value)| (word.to_ascii_lowercase(), *value))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// The function `is_case_insensitive` tells whether the matcher ignores ASCII case.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// The function `longest_word_wins` toggles longest-match resolution. When enabled, only the longest word starting
+    /// at a given position counts, so a longer word hides the shorter words it begins with; if that longer word is a
+    /// no-op mapped above nine, such as "nineteen" in `english_with_teens`, no digit is read there at all. When
+    /// disabled (the default), every word counts, so "nineteen" yields a 9 from its embedded "nine".
+    ///
+    /// Arguments:
+    ///
+    /// * `longest_word_wins`: The `longest_word_wins` parameter is a `bool` enabling or disabling the option.
+    ///
+    /// Returns:
+    ///
+    /// The function `longest_word_wins` returns the updated `DigitMatcher`.
+    pub fn longest_word_wins(mut self, longest_word_wins: bool) -> DigitMatcher {
+        self.longest_word_wins = longest_word_wins;
+        self
+    }
+
+    /// The function `is_longest_word_wins` tells whether only the longest word starting at a position counts.
+    pub fn is_longest_word_wins(&self) -> bool {
+        self.longest_word_wins
+    }
+
+    /// The function `reversed` spells every word of the matcher backwards, for puzzle variants whose lines are
+    /// reversed: `DigitMatcher::english().reversed()` finds a 1 in `"eno"`. The values are kept, and the line is still
+    /// scanned from left to right.
+    ///
+    /// Returns:
+    ///
+    /// The function `reversed` returns the updated `DigitMatcher`.
+    pub fn reversed(mut self) -> DigitMatcher {
+        for (word, _) in self.words.iter_mut().chain(&mut self.lowercase_words) {
+            *word = word.chars().rev().collect();
+        }
+        self
+    }
+
+    /// The function `whole_words` toggles word-boundary matching. By default words match anywhere as plain
+    /// substrings, as the puzzle requires, so "ninepin" yields a 9. When enabled, a word only counts when it isn't
+    /// directly preceded or followed by a letter, so "ninepin" yields no spelled digit while "9-nine" still does.
+    ///
+    /// Arguments:
+    ///
+    /// * `whole_words`: The `whole_words` parameter is a `bool` enabling or disabling the option.
+    ///
+    /// Returns:
+    ///
+    /// The function `whole_words` returns the updated `DigitMatcher`.
+    pub fn whole_words(mut self, whole_words: bool) -> DigitMatcher {
+        self.whole_words = whole_words;
+        self
+    }
+
+    /// The function `is_whole_words` tells whether words only count when they aren't flanked by letters.
+    pub fn is_whole_words(&self) -> bool {
+        self.whole_words
+    }
+
+    /// The function `prefer_words` selects who wins when a numeral and a word start at the same index when looking for
+    /// the first digit, which only happens with words beginning with a numeral, such as "1st": the numeral by default,
+    /// the word when enabled. Digits starting at different indices are still ranked by position.
+    ///
+    /// Arguments:
+    ///
+    /// * `prefer_words`: The `prefer_words` parameter is a `bool` enabling or disabling the option.
+    ///
+    /// Returns:
+    ///
+    /// The function `prefer_words` returns the updated `DigitMatcher`.
+    pub fn prefer_words(mut self, prefer_words: bool) -> DigitMatcher {
+        self.prefer_words = prefer_words;
+        self
+    }
+
+    /// The function `is_prefer_words` tells whether a word beats a numeral starting at the same index.
+    pub fn is_prefer_words(&self) -> bool {
+        self.prefer_words
+    }
+
+    /// The function `is_bounded` tells whether the word found between the byte indices `start` and `end` of the line
+    /// counts: always in substring mode, and only when no letter is directly next to it with `whole_words` enabled.
+    fn is_bounded(&self, line: &str, start: usize, end: usize) -> bool {
+        !self.whole_words
+            || (!line[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphabetic)
+                && !line[end..].chars().next().is_some_and(char::is_alphabetic))
+    }
+
+    /// The function `longest_digit_at` finds the longest word starting at byte index `start` of the line and returns
+    /// the digit it stands for along with the index where it ends, or `None` when no word starts there or the longest
+    /// one is a no-op or isn't bounded as `whole_words` requires.
+    fn longest_digit_at(&self, line: &str, start: usize) -> Option<(Digit, usize)> {
+        let (word, value) = self
+            .matched_words()
+            .iter()
+            .filter(|(word, _)| line[start..].starts_with(word.as_str()))
+            .max_by_key(|(word, _)| word.len())?;
+        let end = start + word.len();
+        if !self.is_bounded(line, start, end) {
+            return None;
+        }
+
+        Some((Digit::new(*value)?, end))
+    }
+
+    /// The function `normalize` prepares a line for scanning: it lowercases the ASCII letters of the line when the
+    /// matcher is case-insensitive, and borrows it unchanged otherwise. Byte offsets are preserved either way.
+    fn normalize<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        if self.case_insensitive && line.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            Cow::Owned(line.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(line)
+        }
+    }
+
+    /// The function `english` builds the default matcher, recognising the English words from `DIGITS`.
+    pub fn english() -> DigitMatcher {
+        DigitMatcher::new(DIGITS.iter().zip(0..).map(|(&word, value)| (word, value)))
+    }
+
+    /// The function `english_with_teens` builds the English matcher extended with "ten" through "nineteen" as no-op
+    /// words mapped to their own value. They never produce a digit themselves, but combined with `longest_word_wins`
+    /// they stop "nineteen" from being read as a 9.
+    pub fn english_with_teens() -> DigitMatcher {
+        let teens = [
+            "ten",
+            "eleven",
+            "twelve",
+            "thirteen",
+            "fourteen",
+            "fifteen",
+            "sixteen",
+            "seventeen",
+            "eighteen",
+            "nineteen",
+        ];
+
+        DigitMatcher::new(
+            DIGITS
+                .iter()
+                .chain(teens.iter())
+                .zip(0..)
+                .map(|(&word, value)| (word, value)),
+        )
+    }
+
+    /// The function `french` builds a matcher recognising the French words for one through nine. French overlaps
+    /// differ from the English ones: "cinquatre" holds both "cinq" and "quatre", "huitrois" holds both "huit" and
+    /// "trois", and the short "un" is also found inside unrelated words such as "aucun".
+    pub fn french() -> DigitMatcher {
+        DigitMatcher::new([
+            ("un", 1),
+            ("deux", 2),
+            ("trois", 3),
+            ("quatre", 4),
+            ("cinq", 5),
+            ("six", 6),
+            ("sept", 7),
+            ("huit", 8),
+            ("neuf", 9),
+        ])
+    }
+
+    /// The function `german` builds a matcher recognising the German words for one through nine. German overlaps
+    /// differ from the English ones: "siebeneun" holds both "sieben" and "neun", while "acht" never overlaps another
+    /// word, so "achtacht" simply reads as two eights.
+    pub fn german() -> DigitMatcher {
+        DigitMatcher::new([
+            ("eins", 1),
+            ("zwei", 2),
+            ("drei", 3),
+            ("vier", 4),
+            ("fünf", 5),
+            ("sechs", 6),
+            ("sieben", 7),
+            ("acht", 8),
+            ("neun", 9),
+        ])
+    }
+
+    /// The function `validate` checks the word list for mistakes that lead to surprising matches: empty words, words
+    /// listed more than once, and digit words that are a prefix of another digit word, such as "on" and "one". No-op
+    /// words mapped above nine may extend a digit word, as "nineteen" does in `english_with_teens`, since they never
+    /// produce a digit themselves. The built-in presets all pass.
+    ///
+    /// Returns:
+    ///
+    /// The function `validate` returns a `Result<(), MatcherError>` describing the first problem found.
+    pub fn validate(&self) -> Result<(), MatcherError> {
+        for (idx, (word, value)) in self.words.iter().enumerate() {
+            if word.is_empty() {
+                return Err(MatcherError::EmptyWord);
+            }
+
+            for (other, other_value) in &self.words[idx + 1..] {
+                if word == other {
+                    return Err(MatcherError::DuplicateWord(word.clone()));
+                }
+                if *value > 9 || *other_value > 9 {
+                    continue;
+                }
+                if other.starts_with(word.as_str()) {
+                    return Err(MatcherError::PrefixOverlap {
+                        prefix: word.clone(),
+                        word: other.clone(),
+                    });
+                }
+                if word.starts_with(other.as_str()) {
+                    return Err(MatcherError::PrefixOverlap {
+                        prefix: other.clone(),
+                        word: word.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The function `words` returns the `(word, value)` pairs recognised by the matcher.
+    pub fn words(&self) -> &[(String, u8)] {
+        &self.words
+    }
+
+    /// The function `matched_words` returns the words lines are scanned for: their lowercased copy when the matcher is
+    /// case-insensitive, and the words as given otherwise.
+    fn matched_words(&self) -> &[(String, u8)] {
+        if self.case_insensitive {
+            &self.lowercase_words
+        } else {
+            &self.words
+        }
+    }
+
+    /// The function `digits` iterates over the words lines are scanned for, together with their value as a `Digit`,
+    /// skipping words whose value isn't a single digit.
+    fn digits(&self) -> impl Iterator<Item = (&str, Digit)> {
+        self.matched_words()
+            .iter()
+            .filter_map(|(word, value)| Some((word.as_str(), Digit::new(*value)?)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DigitMatcher {
+    fn default() -> DigitMatcher {
+        DigitMatcher::english()
+    }
+}
+
+/// The function `english_matcher` returns a shared English `DigitMatcher`, built on first use, so the functions that
+/// don't take a matcher don't rebuild the word list for every line.
+#[cfg(feature = "std")]
+fn english_matcher() -> &'static DigitMatcher {
+    static ENGLISH: OnceLock<DigitMatcher> = OnceLock::new();
+    ENGLISH.get_or_init(DigitMatcher::english)
+}
+
+/// The function `find_first_alpha_digit` takes a string as input and returns the first occurrence of a substring that
+/// consists of either alphabetic characters or digits, along with its index in the original string.
+///
+/// Words are matched as plain substrings, so separators such as hyphens, spaces or punctuation neither block nor
+/// create a match: `"one-two-three"` finds "one", "two" and "three", and `"twenty-one"` finds only "one". Letters don't
+/// block a match either, so "nine" is found inside `"ninepin"`, unless the matcher has `whole_words` enabled. Lines are
+/// scanned independently, so a word can never span two of them.
+///
+/// Arguments:
+///
+/// * `str`: The `str` parameter is a reference to a string (`&str`). It represents the input string in which we want to
+///   find the first occurrence of a substring that consists of either an alphabetic character or a digit.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the words to look for.
+///
+/// Returns:
+///
+/// The function `find_first_alpha_digit` returns an `Option` containing a tuple `(Digit, usize)`.
+#[cfg(feature = "std")]
+fn find_first_alpha_digit(str: &str, matcher: &DigitMatcher) -> Option<(Digit, usize)> {
+    if matcher.longest_word_wins {
+        return str.char_indices().find_map(|(idx, _)| {
+            matcher
+                .longest_digit_at(str, idx)
+                .map(|(digit, _)| (digit, idx))
+        });
+    }
+
+    matcher
+        .digits()
+        .filter_map(|(substring, digit)| {
+            str.match_indices(substring)
+                .map(|(index, _)| index)
+                .find(|&index| matcher.is_bounded(str, index, index + substring.len()))
+                .map(|index| (digit, index))
+        })
+        .min_by_key(|&(_, index)| index)
+}
+
+/// The function `find_last_alpha_digit` finds the last occurrence of a substring consisting of only alphabetic characters
+/// and digits in a given string and returns the substring along with the index where it ends.
+///
+/// The line is walked once from the end, checking at each character boundary whether any word ends there, so the first
+/// match found is the rightmost-ending one, even when a custom `DigitMatcher` has a word nested inside another. This
+/// costs O(length × words) instead of one `rfind` pass over the line per word. Overlapping words are treated as two
+/// distinct digits, so `"oneight"` yields `"eight"`, `"twone"` yields `"one"` and `"eightwo"` yields `"two"`.
+///
+/// Arguments:
+///
+/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
+///   search for the last occurrence of a substring that consists of both alphabetic characters and digits.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the words to look for.
+///
+/// Returns:
+///
+/// The function `find_last_alpha_digit` returns an `Option<(Digit, usize)>`, where the index is the end offset of the
+/// word.
+#[cfg(feature = "std")]
+fn find_last_alpha_digit(str: &str, matcher: &DigitMatcher) -> Option<(Digit, usize)> {
+    if matcher.longest_word_wins {
+        return str
+            .char_indices()
+            .filter_map(|(idx, _)| matcher.longest_digit_at(str, idx))
+            .max_by_key(|&(_, end)| end);
+    }
+
+    let last = str
+        .char_indices()
+        .rev()
+        .map(|(idx, char)| idx + char.len_utf8())
+        .find_map(|end| {
+            matcher
+                .digits()
+                .find(|(substring, _)| {
+                    str[..end].ends_with(substring)
+                        && matcher.is_bounded(str, end - substring.len(), end)
+                })
+                .map(|(_, digit)| (digit, end))
+        });
+
+    #[cfg(feature = "test-util")]
+    if !matcher.whole_words {
+        debug_assert_eq!(
+            last.map(|(_, end)| end),
+            rfind_last_alpha_digit(str, matcher).map(|(_, end)| end),
+            "reverse scan and rfind disagree on {:?}",
+            str
+        );
+    }
+
+    last
+}
+
+/// The function `rfind_last_alpha_digit` is the reference implementation `find_last_alpha_digit` replaced: it calls
+/// `rfind` once per word and keeps the match ending last. With the `test-util` feature enabled, debug builds check the
+/// single reverse pass against it on every line, which the fuzz target exercises. Only the end offsets are compared,
+/// since two words can end at the same index, and matchers with `whole_words` enabled are skipped, because `rfind` only
+/// sees the last occurrence of each word even when it isn't bounded.
+///
+/// Arguments:
+///
+/// * `str`: The `str` parameter is a string slice (`&str`) that represents a line of text.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the words to look for.
+///
+/// Returns:
+///
+/// The function `rfind_last_alpha_digit` returns an `Option<(Digit, usize)>`, where the index is the end offset of the
+/// word.
+#[cfg(feature = "test-util")]
+fn rfind_last_alpha_digit(str: &str, matcher: &DigitMatcher) -> Option<(Digit, usize)> {
+    let mut last_end = None;
+    let mut last_substring = None;
+
+    for (substring, digit) in matcher.digits() {
+        if let Some(index) = str.rfind(substring) {
+            let end = index + substring.len();
+            if last_end.is_none_or(|last| end > last) {
+                last_end = Some(end);
+                last_substring = Some((digit, end));
+            }
+        }
+    }
+
+    last_substring
+}
+
+/// The `DIGIT_WORDS` table pairs each word of `DIGITS` with its value, built at compile time from `DIGITS`.
+const DIGIT_WORDS: [(&str, u8); DIGITS.len()] = {
+    let mut words = [("", 0); DIGITS.len()];
+    let mut idx = 0;
+    while idx < DIGITS.len() {
+        words[idx] = (DIGITS[idx], idx as u8);
+        idx += 1;
+    }
+    words
+};
+
+/// The function `digit_words` returns the spelled-out English digits the crate recognises, each paired with its value,
+/// from `("zero", 0)` to `("nine", 9)`. A word's value is its index in the slice, not the index plus one.
+///
+/// Returns:
+///
+/// The function `digit_words` returns a `&'static [(&'static str, u8)]` ordered by value.
+pub fn digit_words() -> &'static [(&'static str, u8)] {
+    &DIGIT_WORDS
+}
+
+/// The function `word_to_digit` takes a spelled-out English digit and returns its numeric value, or `None` if the input
+/// is not one of the words in `DIGITS`.
+///
+/// Arguments:
+///
+/// * `word`: The `word` parameter is a string slice (`&str`) that represents an alphabetic digit.
+///
+/// Returns:
+///
+/// The function `word_to_digit` returns an `Option<u8>`.
+pub fn word_to_digit(word: &str) -> Option<u8> {
+    match word {
+        "zero" => Some(0),
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        _ => None,
+    }
+}
+
+/// The function `ascii_digit` returns the value of an ASCII digit `0`-`9`, the only characters ever read as numerals.
+/// Characters that `char::is_numeric` accepts but that aren't ASCII digits, such as the Roman numeral `'Ⅻ'`, the
+/// superscript `'²'` or the fullwidth `'１'`, are deliberately ignored, so that pasted text can't silently change the
+/// answer.
+fn ascii_digit(char: char) -> Option<u8> {
+    char.is_ascii_digit().then(|| char as u8 - b'0')
+}
+
+/// The function `find_first_ascii_digit` scans raw bytes for the first ASCII digit `b'0'..=b'9'` and returns its value
+/// along with its byte index, without decoding UTF-8. `b"ab3cd"` yields `(3, 2)`.
+///
+/// Arguments:
+///
+/// * `bytes`: The `bytes` parameter is a byte slice (`&[u8]`), such as the bytes of an ASCII line.
+///
+/// Returns:
+///
+/// The function `find_first_ascii_digit` returns an `Option<(u8, usize)>` holding the digit's value and index.
+#[inline]
+pub fn find_first_ascii_digit(bytes: &[u8]) -> Option<(u8, usize)> {
+    bytes
+        .iter()
+        .position(u8::is_ascii_digit)
+        .map(|idx| (bytes[idx] - b'0', idx))
+}
+
+/// The function `find_last_ascii_digit` scans raw bytes from the end for the last ASCII digit `b'0'..=b'9'` and returns
+/// its value along with its byte index, without decoding UTF-8. `b"ab3c4d"` yields `(4, 4)`.
+///
+/// Arguments:
+///
+/// * `bytes`: The `bytes` parameter is a byte slice (`&[u8]`), such as the bytes of a line.
+///
+/// Returns:
+///
+/// The function `find_last_ascii_digit` returns an `Option<(u8, usize)>` holding the digit's value and index.
+#[inline]
+pub fn find_last_ascii_digit(bytes: &[u8]) -> Option<(u8, usize)> {
+    bytes
+        .iter()
+        .rposition(u8::is_ascii_digit)
+        .map(|idx| (bytes[idx] - b'0', idx))
+}
+
+/// The function `find_first_numeric_digit` takes a string as input and returns the first numeric digit found along with
+/// its byte index, if any. Byte indices are used so that they can be compared with the ones reported by `str::find`
+/// for spelled digits, even when the line contains multi-byte characters. The line is scanned byte by byte with
+/// `find_first_ascii_digit`, which is also correct for non-ASCII lines: in UTF-8, the bytes of a multi-byte character
+/// are never below `0x80`, so an ASCII digit byte is always a whole character. Only the ASCII digits are read as
+/// numerals, so `"１abc2"` yields 22 and `"Ⅻ"` or `"²"` yield no digit at all.
+///
+/// Arguments:
+///
+/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
+///   search for the first numeric digit.
+fn find_first_numeric_digit(str: &str) -> Option<(Digit, usize)> {
+    find_first_ascii_digit(str.as_bytes()).map(|(value, idx)| (Digit(value), idx))
+}
+
+/// The function `find_last_numeric_digit` takes a string as input and returns the last numeric digit found in the string
+/// along with its byte index, if any, scanning the bytes from the end with `find_last_ascii_digit`.
+///
+/// Arguments:
+///
+/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
+///   search for the last numeric digit.
+fn find_last_numeric_digit(str: &str) -> Option<(Digit, usize)> {
+    find_last_ascii_digit(str.as_bytes()).map(|(value, idx)| (Digit(value), idx))
+}
+
+/// The function `digit_at` checks whether a numeric digit or a spelled digit from `DIGITS` begins with the character
+/// found at byte index `idx` of the line, returning its value.
+fn digit_at(line: &str, idx: usize, char: char) -> Option<u8> {
+    typed_digit_at(line, idx, char).map(|(value, _)| value)
+}
+
+/// The function `typed_digit_at` works like `digit_at`, but also reports whether the digit was a numeral or a word.
+fn typed_digit_at(line: &str, idx: usize, char: char) -> Option<(u8, Source)> {
+    match ascii_digit(char) {
+        Some(value) => Some((value, Source::Numeral)),
+        None => DIGITS
+            .iter()
+            .position(|&word| line[idx..].starts_with(word))
+            .map(|position| (position as u8, Source::Word)),
+    }
+}
+
+/// The function `scan_digits` walks the characters of a line from left to right exactly once and, at each position,
+/// checks whether a numeric digit or a spelled digit from `DIGITS` begins there, recording the first and last digit
+/// found in that single pass.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `scan_digits` returns a tuple `(Option<u8>, Option<u8>)` holding the numeric values of the first and
+/// last digit of the line. Both elements are `None` when the line contains no digit.
+pub fn scan_digits(line: &str) -> (Option<u8>, Option<u8>) {
+    let mut first = None;
+    let mut last = None;
+
+    for (idx, char) in line.char_indices() {
+        let digit = digit_at(line, idx, char);
+
+        if digit.is_some() {
+            first = first.or(digit);
+            last = digit;
+        }
+    }
+
+    (first, last)
+}
+
+/// The function `first_last_digits` returns both the first and the last digit of a line, computed in the single shared
+/// traversal of `scan_digits` rather than in separate scans. For a line holding a single digit, both elements equal
+/// that digit, as the puzzle requires for `"treb7uchet"`: should only one end of the scan be reported, that digit is
+/// explicitly duplicated instead of the line being dropped, so `"a5b"` yields `(5, 5)`. Lines containing none of the
+/// letters spelled digits start with, such as `"12x34"`, skip the word matching and only look for numerals.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `first_last_digits` returns an `Option<(u8, u8)>`, which is `None` when the line contains no digit.
+pub fn first_last_digits(line: &str) -> Option<(u8, u8)> {
+    if !may_spell_digit(line) {
+        let (first, _) = find_first_numeric_digit(line)?;
+        let (last, _) = find_last_numeric_digit(line)?;
+        return Some((first.value(), last.value()));
+    }
+
+    match scan_digits(line) {
+        (Some(first), Some(last)) => Some((first, last)),
+        (Some(digit), None) | (None, Some(digit)) => Some((digit, digit)),
+        (None, None) => None,
+    }
+}
+
+/// The function `digits_in` yields every numeric and spelled English digit of a line in reading order, ranked by where
+/// they start. Overlapping words each count as a digit of their own, so `"oneight"` yields 1 then 8.
+fn digits_in(line: &str) -> impl DoubleEndedIterator<Item = u8> + '_ {
+    line.char_indices()
+        .filter_map(move |(idx, char)| digit_at(line, idx, char))
+}
+
+/// The function `all_digits` returns every numeric and spelled English digit of a line, in the order they start.
+/// Overlapping words are all included, so `"eightwothree"` yields `[8, 2, 3]`, and the first and last elements are the
+/// digits `get_coord` combines. `get_coord` itself doesn't use it, to avoid allocating a vector per line.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `all_digits` returns a `Vec<u8>`, which is empty when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn all_digits(line: &str) -> Vec<u8> {
+    digits_in(line).collect()
+}
+
+/// The function `all_digits_typed` returns the digits of a line like `all_digits`, each paired with the `Source` it was
+/// read from, so `"7seven"` yields `[(7, Source::Numeral), (7, Source::Word)]`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `all_digits_typed` returns a `Vec<(u8, Source)>`, which is empty when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn all_digits_typed(line: &str) -> Vec<(u8, Source)> {
+    line.char_indices()
+        .filter_map(|(idx, char)| typed_digit_at(line, idx, char))
+        .collect()
+}
+
+/// The function `score_line` sums every digit of a line as found by `all_digits_typed`, a spelled word counting for its
+/// value and a numeral for its value times `numeral_multiplier`. With a multiplier of 2, `"one2three"` scores
+/// `1 + 4 + 3 = 8`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `numeral_multiplier`: The `numeral_multiplier` parameter is the `u32` factor applied to numeral digits.
+///
+/// Returns:
+///
+/// The function `score_line` returns the `u32` score, which is zero when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn score_line(line: &str, numeral_multiplier: u32) -> u32 {
+    all_digits_typed(line)
+        .into_iter()
+        .map(|(value, source)| match source {
+            Source::Numeral => u32::from(value) * numeral_multiplier,
+            Source::Word => u32::from(value),
+        })
+        .sum()
+}
+
+/// The function `all_digits_with` returns the digits of a line like `all_digits`, but lets overlapping words be
+/// excluded: with `allow_overlap` set, every word counts and `"sevenine"` yields `[7, 9]`; without it, the scan resumes
+/// after the end of each matched word, so the "nine" sharing its first letter with "seven" is dropped and `"sevenine"`
+/// yields `[7]`. The first and last digits `get_coord` uses are those of the overlapping mode.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `allow_overlap`: The `allow_overlap` parameter tells whether words sharing letters all count.
+///
+/// Returns:
+///
+/// The function `all_digits_with` returns a `Vec<u8>`, which is empty when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn all_digits_with(line: &str, allow_overlap: bool) -> Vec<u8> {
+    if allow_overlap {
+        return all_digits(line);
+    }
+
+    let mut digits = Vec::new();
+    let mut resume = 0;
+    for (idx, char) in line.char_indices() {
+        if idx < resume {
+            continue;
+        }
+
+        if let Some(value) = ascii_digit(char) {
+            digits.push(value);
+        } else if let Some(position) = DIGITS
+            .iter()
+            .position(|&word| line[idx..].starts_with(word))
+        {
+            digits.push(position as u8);
+            resume = idx + DIGITS[position].len();
+        }
+    }
+
+    digits
+}
+
+/// The function `nth_digit` returns the digit found at position `n` (counting from zero) among all the numeric and
+/// spelled digits of a line, so `n = 0` gives the first digit and `"two1nine"` yields 1 for `n = 1`. Overlapping words
+/// count separately, as two digits: `"oneight"` yields 8 for `n = 1`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `n`: The `n` parameter is the zero-based position of the digit, counting from the start of the line.
+///
+/// Returns:
+///
+/// The function `nth_digit` returns an `Option<u8>`, which is `None` when the line holds `n` digits or fewer.
+pub fn nth_digit(line: &str, n: usize) -> Option<u8> {
+    digits_in(line).nth(n)
+}
+
+/// The function `nth_last_digit` returns the digit found at position `n` (counting from zero) among all the numeric and
+/// spelled digits of a line, counting from its end, so `n = 0` gives the digit starting last. Overlapping words count
+/// separately, as in `nth_digit`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `n`: The `n` parameter is the zero-based position of the digit, counting from the end of the line.
+///
+/// Returns:
+///
+/// The function `nth_last_digit` returns an `Option<u8>`, which is `None` when the line holds `n` digits or fewer.
+pub fn nth_last_digit(line: &str, n: usize) -> Option<u8> {
+    digits_in(line).rev().nth(n)
+}
+
+/// The function `get_first_digit` returns the first numeric or alphabetic digit found in a given string, prioritizing
+/// numeric digits over alphabetic digits: when a numeral and a word start at the same index, which only a custom
+/// `DigitMatcher` with words beginning with a numeral can produce, the numeral wins unless the matcher has
+/// `prefer_words` enabled.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string that represents a line of text.
+///
+/// Returns:
+///
+/// The function `get_first_digit` returns an `Option<Digit>`.
+#[cfg(feature = "std")]
+pub fn get_first_digit(line: &str) -> Option<Digit> {
+    get_first_digit_with(line, english_matcher())
+}
+
+/// The function `get_first_digit_with` behaves like `get_first_digit`, but recognises the spelled digits of the given
+/// `DigitMatcher` instead of the English ones.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string that represents a line of text.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the spelled digits.
+///
+/// Returns:
+///
+/// The function `get_first_digit_with` returns an `Option<Digit>`.
+#[cfg(feature = "std")]
+pub fn get_first_digit_with(line: &str, matcher: &DigitMatcher) -> Option<Digit> {
+    pick_first_digit(&matcher.normalize(line), matcher)
+}
+
+/// The function `pick_first_digit` picks the first numeric or alphabetic digit of a line that has already been
+/// normalized for the given `DigitMatcher`.
+#[cfg(feature = "std")]
+fn pick_first_digit(line: &str, matcher: &DigitMatcher) -> Option<Digit> {
+    let first_numeric_digit = find_first_numeric_digit(line);
+    let first_alpha_digit = find_first_alpha_digit(line, matcher);
+
+    match (first_numeric_digit, first_alpha_digit) {
+        (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_idx))) => {
+            // Ties go to the numeral unless words are preferred, see `get_first_digit`.
+            if numeric_idx < alpha_idx || (numeric_idx == alpha_idx && !matcher.prefer_words) {
+                Some(numeric_digit)
+            } else {
+                Some(alpha_digit)
+            }
+        }
+        (Some((numeric_digit, _)), None) => Some(numeric_digit),
+        (None, Some((alpha_digit, _))) => Some(alpha_digit),
+        _ => None,
+    }
+}
+
+/// The function `get_last_digit` takes a string as input and returns the last numeric or alphabetic digit found in the
+/// string, converting alphabetic digits to their corresponding numeric values if necessary. Matches are ranked by
+/// where they end, so the rightmost-ending numeral or word wins.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string that represents a line of text.
+///
+/// Returns:
+///
+/// The function `get_last_digit` returns an `Option<Digit>`.
+#[cfg(feature = "std")]
+pub fn get_last_digit(line: &str) -> Option<Digit> {
+    get_last_digit_with(line, english_matcher())
+}
+
+/// The function `get_last_digit_with` behaves like `get_last_digit`, but recognises the spelled digits of the given
+/// `DigitMatcher` instead of the English ones.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string that represents a line of text.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the spelled digits.
+///
+/// Returns:
+///
+/// The function `get_last_digit_with` returns an `Option<Digit>`.
+#[cfg(feature = "std")]
+pub fn get_last_digit_with(line: &str, matcher: &DigitMatcher) -> Option<Digit> {
+    pick_last_digit(&matcher.normalize(line), matcher)
+}
+
+/// The function `first_digit` returns the numeric value of the first digit of a line, as found by `get_first_digit`, so
+/// `"eightwothree"` yields 8.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `first_digit` returns an `Option<u8>`, which is `None` when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn first_digit(line: &str) -> Option<u8> {
+    get_first_digit(line).map(Digit::value)
+}
+
+/// The function `last_digit` returns the numeric value of the last digit of a line, as found by `get_last_digit`, so
+/// `"eightwothree"` yields 3.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `last_digit` returns an `Option<u8>`, which is `None` when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn last_digit(line: &str) -> Option<u8> {
+    get_last_digit(line).map(Digit::value)
+}
+
+/// The function `pick_last_digit` picks the last numeric or alphabetic digit of a line that has already been
+/// normalized for the given `DigitMatcher`.
+#[cfg(feature = "std")]
+fn pick_last_digit(line: &str, matcher: &DigitMatcher) -> Option<Digit> {
+    let last_numeric_digit = find_last_numeric_digit(line);
+    let last_alpha_digit = find_last_alpha_digit(line, matcher);
+
+    match (last_numeric_digit, last_alpha_digit) {
+        (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_end))) => {
+            // A numeral is a single ASCII byte, so it ends right after its index.
+            if numeric_idx + 1 > alpha_end {
+                Some(numeric_digit)
+            } else {
+                Some(alpha_digit)
+            }
+        }
+        (Some((numeric_digit, _)), None) => Some(numeric_digit),
+        (None, Some((alpha_digit, _))) => Some(alpha_digit),
+        _ => None,
+    }
+}
+
+/// The function `find_first_digit_span` returns the first numeric or alphabetic digit of a line together with the byte
+/// range of the text that produced it: one byte for a numeral, the whole word for a spelled digit.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `find_first_digit_span` returns an `Option<(u8, Range<usize>)>`, so that `line[range]` is the matched
+/// text.
+#[cfg(feature = "std")]
+pub fn find_first_digit_span(line: &str) -> Option<(u8, Range<usize>)> {
+    let numeric_span = line
+        .char_indices()
+        .find_map(|(idx, char)| ascii_digit(char).map(|value| (value, idx..idx + char.len_utf8())));
+    let alpha_span = english_matcher()
+        .digits()
+        .filter_map(|(word, digit)| {
+            line.find(word)
+                .map(|idx| (digit.value(), idx..idx + word.len()))
+        })
+        .min_by_key(|(_, span)| span.start);
+
+    match (numeric_span, alpha_span) {
+        (Some(numeric), Some(alpha)) if numeric.1.start <= alpha.1.start => Some(numeric),
+        (_, Some(alpha)) => Some(alpha),
+        (numeric, None) => numeric,
+    }
+}
+
+/// The function `find_last_digit_span` returns the last numeric or alphabetic digit of a line together with the byte
+/// range of the text that produced it: one byte for a numeral, the whole word for a spelled digit.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `find_last_digit_span` returns an `Option<(u8, Range<usize>)>`, so that `line[range]` is the matched
+/// text.
+#[cfg(feature = "std")]
+pub fn find_last_digit_span(line: &str) -> Option<(u8, Range<usize>)> {
+    let numeric_span = line
+        .char_indices()
+        .rev()
+        .find_map(|(idx, char)| ascii_digit(char).map(|value| (value, idx..idx + char.len_utf8())));
+    let alpha_span = english_matcher()
+        .digits()
+        .filter_map(|(word, digit)| {
+            line.rfind(word)
+                .map(|idx| (digit.value(), idx..idx + word.len()))
+        })
+        .max_by_key(|(_, span)| span.start);
+
+    match (numeric_span, alpha_span) {
+        (Some(numeric), Some(alpha)) if numeric.1.start > alpha.1.start => Some(numeric),
+        (_, Some(alpha)) => Some(alpha),
+        (numeric, None) => numeric,
+    }
+}
+
+/// The function `matched_tokens` returns the source text that produced the first and the last digit of a line, a
+/// numeral such as "7" or a spelled word such as "one", rather than their values. `"abcone2threexyz"` yields
+/// `("one", "three")`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `matched_tokens` returns an `Option<(String, String)>`, which is `None` when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn matched_tokens(line: &str) -> Option<(String, String)> {
+    let (_, first) = find_first_digit_span(line)?;
+    let (_, last) = find_last_digit_span(line)?;
+
+    Some((line[first].to_string(), line[last].to_string()))
+}
+
+/// The function `overlapping_words` returns every spelled English digit found in a line together with its byte range,
+/// including matches that share characters, such as "one" (0..3) and "eight" (2..7) in "oneight". It is a diagnostic
+/// for spotting such overlaps and plays no part in solving.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `overlapping_words` returns a `Vec<(Range<usize>, u8)>` of the matches ordered by their start.
+#[cfg(feature = "std")]
+pub fn overlapping_words(line: &str) -> Vec<(Range<usize>, u8)> {
+    line.char_indices()
+        .flat_map(|(idx, _)| {
+            english_matcher()
+                .digits()
+                .filter(move |(word, _)| line[idx..].starts_with(word))
+                .map(move |(word, digit)| (idx..idx + word.len(), digit.value()))
+        })
+        .collect()
+}
+
+/// The function `is_ambiguous` tells whether the first or the last digit of a line is a spelled word sharing letters
+/// with another word, as in `"eightwothree"` or `"xtwone3"`. These are the lines where naively replacing the words
+/// with numerals before scanning can give a different coordinate than reading every word in place. Lines such as
+/// `"1abc2"`, or whose overlaps are hidden between the first and the last digit, aren't ambiguous.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `is_ambiguous` returns a `bool`, which is `false` when the line contains no digit.
+#[cfg(feature = "std")]
+pub fn is_ambiguous(line: &str) -> bool {
+    let (Some((_, first)), Some((_, last))) =
+        (find_first_digit_span(line), find_last_digit_span(line))
+    else {
+        return false;
+    };
+
+    let words = overlapping_words(line);
+    let overlaps = |span: &Range<usize>| {
+        words
+            .iter()
+            .any(|(word, _)| word != span && word.start < span.end && span.start < word.end)
+    };
+
+    overlaps(&first) || overlaps(&last)
+}
+
+/// The function `get_coord` extracts the first and last digit from a given string and combines them into a two-digit
+/// unsigned 32-bit integer, using the first digit as the tens and the last digit as the units. Lines without any digit
+/// yield `None` instead of panicking. Digits are read as `u8` values and combined arithmetically, so no heap allocation
+/// happens per line.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `get_coord` returns an `Option<u32>`, which is `None` when the line contains no digits at all.
+pub fn get_coord(line: &str) -> Option<u32> {
+    get_coord_for(line, Part::Two)
+}
+
+/// The function `get_coord_reversed` computes the coordinate of a line as if it were read from right to left: the last
+/// digit becomes the tens and the first digit the units, so `"two1nine"` yields 92 where `get_coord` yields 29.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `get_coord_reversed` returns an `Option<u32>`, which is `None` when the line contains no digits at all.
+pub fn get_coord_reversed(line: &str) -> Option<u32> {
+    let (first_digit, last_digit) = first_last_digits(line)?;
+
+    Some(combine_digits(Digit(last_digit), Digit(first_digit)))
+}
+
+/// The function `try_get_coord` behaves like `get_coord`, but reports why a line has no coordinate through a typed
+/// error instead of a bare `None`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `try_get_coord` returns a `Result<u32, CoordError>`, with `CoordError::NoDigits` when the line
+/// contains no digits at all.
+pub fn try_get_coord(line: &str) -> Result<u32, CoordError> {
+    get_coord(line).ok_or(CoordError::NoDigits)
+}
+
+/// The function `get_coord_for` computes the coordinate of a line according to the rules of the given puzzle part:
+/// `Part::One` only considers numeric digits, while `Part::Two` also considers spelled digits from `DIGITS`.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `part`: The `part` parameter selects which puzzle part's rules are used to find the digits.
+///
+/// Returns:
+///
+/// The function `get_coord_for` returns an `Option<u32>`, which is `None` when the line contains no digits at all.
+pub fn get_coord_for(line: &str, part: Part) -> Option<u32> {
+    match part {
+        Part::One => {
+            let (first_digit, _) = find_first_numeric_digit(line)?;
+            let (last_digit, _) = find_last_numeric_digit(line)?;
+
+            Some(combine_digits(first_digit, last_digit))
+        }
+        Part::Two => {
+            let (first_digit, last_digit) = first_last_digits(line)?;
+
+            Some(combine_digits(Digit(first_digit), Digit(last_digit)))
+        }
+    }
+}
+
+/// The function `get_coord_with` computes the Part 2 coordinate of a line, recognising the spelled digits of the given
+/// `DigitMatcher` instead of the English ones.
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the spelled digits.
+///
+/// Returns:
+///
+/// The function `get_coord_with` returns an `Option<u32>`, which is `None` when the line contains no digits at all.
+#[cfg(feature = "std")]
+pub fn get_coord_with(line: &str, matcher: &DigitMatcher) -> Option<u32> {
+    let line = matcher.normalize(line);
+    let first_digit = pick_first_digit(&line, matcher)?;
+    let last_digit = pick_last_digit(&line, matcher)?;
+
+    Some(combine_digits(first_digit, last_digit))
+}
+
+/// The function `combine_digits` builds a two-digit coordinate, using the first digit as the tens and the last digit as
+/// the units. A line holding a single digit passes it as both arguments, so `"treb7uchet"` yields 77. Both digits are at
+/// most nine, so the result always lies in `0..=99` and the arithmetic can't overflow.
+fn combine_digits(first_digit: Digit, last_digit: Digit) -> u32 {
+    u32::from(first_digit.value()) * 10 + u32::from(last_digit.value())
+}
+
+/// The function `coords` lazily yields the coordinate of each line, skipping lines that contain no digits, so callers
+/// can aggregate them however they like (`.sum()`, `.max()`, `.collect()`, ...).
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+///
+/// Returns:
+///
+/// The function `coords` returns an iterator over the `u32` coordinates, borrowing `lines`.
+#[cfg(feature = "std")]
+pub fn coords<'a>(lines: &'a [String]) -> impl Iterator<Item = u32> + 'a {
+    lines.iter().filter_map(|line| get_coord(line))
+}
+
+/// The function `annotated_coords` pairs each line's 1-based line number with its coordinate, keeping the lines that
+/// contain no digits as `None`, so that diagnostic tooling can build a report without re-scanning the input.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+///
+/// Returns:
+///
+/// The function `annotated_coords` returns a `Vec<(usize, Option<u32>)>` in input order.
+#[cfg(feature = "std")]
+pub fn annotated_coords(lines: &[String]) -> Vec<(usize, Option<u32>)> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, get_coord(line)))
+        .collect()
+}
+
+/// The function `lines_with_coord` finds the lines whose coordinate equals `target`, which helps tracing an unexpected
+/// value back to the input. Lines without digits never match.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+/// * `target`: The `target` parameter is the `u32` coordinate to look for.
+///
+/// Returns:
+///
+/// The function `lines_with_coord` returns a `Vec<usize>` of the 0-based indices of the matching lines, in input order.
+#[cfg(feature = "std")]
+pub fn lines_with_coord(lines: &[String], target: u32) -> Vec<usize> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| get_coord(line) == Some(target))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// The function `solve` maps each line through `get_coord` and sums the resulting coordinates, skipping lines that
+/// contain no digits. With the `rayon` feature enabled the lines are processed in parallel.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+///
+/// Returns:
+///
+/// The function `solve` returns the sum of all coordinates as a `u64`, which can't overflow even for inputs far larger
+/// than `u32::MAX / 99` lines.
+#[cfg(feature = "std")]
+pub fn solve(lines: &[String]) -> u64 {
+    solve_for(lines, Part::Two)
+}
+
+/// The function `solve_iter` maps each line yielded by an iterator through `get_coord` and sums the resulting
+/// coordinates, skipping lines that contain no digits. Lines are only borrowed, so lazily produced input never has to be
+/// collected into a vector first.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is any iterable of string-like lines, such as `&str`s or `String`s.
+///
+/// Returns:
+///
+/// The function `solve_iter` returns the sum of all coordinates as a `u64`.
+pub fn solve_iter<I, S>(lines: I) -> u64
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let stats: SolveStats = lines
+        .into_iter()
+        .map(|line| SolveStats::from(log_coord(line.as_ref(), get_coord(line.as_ref()))))
+        .sum();
+
+    log_stats(stats).sum
+}
+
+/// The function `log_coord` reports the coordinate found for a line, or that the line is skipped, as a `log::debug!`
+/// message when the `log` feature is enabled, so that applications embedding the solver can follow it with the logger
+/// of their choice. It returns the coordinate unchanged.
+fn log_coord(line: &str, coord: Option<u32>) -> Option<u32> {
+    match coord {
+        Some(coord) => debug!("line {:?}: coordinate {}", line, coord),
+        None => debug!("line {:?}: skipped, no digits", line),
+    }
+
+    coord
+}
+
+/// The function `log_stats` reports how many lines a solver processed and skipped, and their sum, like `log_coord`
+/// does for each line. It returns the statistics unchanged.
+fn log_stats(stats: SolveStats) -> SolveStats {
+    debug!(
+        "processed {} lines, skipped {}, sum {}",
+        stats.counted + stats.skipped,
+        stats.skipped,
+        stats.sum
+    );
+
+    stats
+}
+
+/// The statistics gathered while solving: the sum of the coordinates and how many lines did or didn't contribute one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SolveStats {
+    pub sum: u64,
+    pub counted: usize,
+    pub skipped: usize,
+}
+
+impl From<Option<u32>> for SolveStats {
+    fn from(coord: Option<u32>) -> SolveStats {
+        match coord {
+            Some(coord) => SolveStats {
+                sum: u64::from(coord),
+                counted: 1,
+                skipped: 0,
+            },
+            None => SolveStats {
+                skipped: 1,
+                ..SolveStats::default()
+            },
+        }
+    }
+}
+
+impl core::ops::Add for SolveStats {
+    type Output = SolveStats;
+
+    fn add(self, other: SolveStats) -> SolveStats {
+        SolveStats {
+            sum: self.sum + other.sum,
+            counted: self.counted + other.counted,
+            skipped: self.skipped + other.skipped,
+        }
+    }
+}
+
+impl core::iter::Sum for SolveStats {
+    fn sum<I: Iterator<Item = SolveStats>>(iter: I) -> SolveStats {
+        iter.fold(SolveStats::default(), |total, stats| total + stats)
+    }
+}
+
+impl FromIterator<Option<u32>> for SolveStats {
+    fn from_iter<I: IntoIterator<Item = Option<u32>>>(iter: I) -> SolveStats {
+        iter.into_iter().map(SolveStats::from).sum()
+    }
+}
+
+/// The function `solve_with_stats` sums the coordinates of the lines like `solve` does, and also counts the lines that
+/// produced a coordinate and the ones skipped because they contain no digits. With the `rayon` feature enabled the
+/// lines are processed in parallel.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+///
+/// Returns:
+///
+/// The function `solve_with_stats` returns a `SolveStats` with the sum and the line counts.
+#[cfg(feature = "std")]
+pub fn solve_with_stats(lines: &[String]) -> SolveStats {
+    solve_lines(lines, get_coord)
+}
+
+/// The summary of a solve: the statistics of `SolveStats` along with the smallest and largest coordinate, which are
+/// `None` when no line produced one. With the `serde` feature enabled it can be serialized and deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveSummary {
+    pub sum: u64,
+    pub counted: usize,
+    pub skipped: usize,
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+}
+
+impl FromIterator<Option<u32>> for SolveSummary {
+    fn from_iter<I: IntoIterator<Item = Option<u32>>>(iter: I) -> SolveSummary {
+        iter.into_iter()
+            .fold(SolveSummary::default(), |mut summary, coord| {
+                match coord {
+                    Some(coord) => {
+                        summary.sum += u64::from(coord);
+                        summary.counted += 1;
+                        summary.min = Some(summary.min.map_or(coord, |min| min.min(coord)));
+                        summary.max = Some(summary.max.map_or(coord, |max| max.max(coord)));
+                    }
+                    None => summary.skipped += 1,
+                }
+                summary
+            })
+    }
+}
+
+/// The function `solve_summary` solves the lines like `solve_with_stats` does, and also records the smallest and
+/// largest coordinate.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+///
+/// Returns:
+///
+/// The function `solve_summary` returns a `SolveSummary` with the sum, the line counts and the coordinate range.
+#[cfg(feature = "std")]
+pub fn solve_summary(lines: &[String]) -> SolveSummary {
+    lines.iter().map(|line| get_coord(line)).collect()
+}
+
+/// A solver keeping a running sum across batches of lines, so that an append-only input only needs its new lines
+/// processed: the running sum always equals what `solve` returns for all the lines fed so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RunningSolver {
+    pub sum: u64,
+    pub processed_lines: usize,
+}
+
+impl RunningSolver {
+    /// The function `feed` adds the coordinates of a batch of new lines to the running sum.
+    ///
+    /// Arguments:
+    ///
+    /// * `new_lines`: The `new_lines` parameter is a slice of `String`s, the lines added since the previous batch.
+    #[cfg(feature = "std")]
+    pub fn feed(&mut self, new_lines: &[String]) {
+        self.sum += solve(new_lines);
+        self.processed_lines += new_lines.len();
+    }
+}
+
+/// The function `solve_for` maps each line through `get_coord_for` with the given puzzle part and sums the resulting
+/// coordinates, skipping lines that contain no digits. With the `rayon` feature enabled the lines are processed in
+/// parallel.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+/// * `part`: The `part` parameter selects which puzzle part's rules are used to find the digits.
+///
+/// Returns:
+///
+/// The function `solve_for` returns the sum of all coordinates as a `u64`.
+#[cfg(feature = "std")]
+pub fn solve_for(lines: &[String], part: Part) -> u64 {
+    solve_lines(lines, |line| get_coord_for(line, part)).sum
+}
+
+/// The function `solve_with` maps each line through `get_coord_with` and sums the resulting coordinates, skipping lines
+/// that contain no digits. With the `rayon` feature enabled the lines are processed in parallel.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+/// * `matcher`: The `matcher` parameter is the `DigitMatcher` providing the spelled digits.
+///
+/// Returns:
+///
+/// The function `solve_with` returns the sum of all coordinates as a `u64`.
+#[cfg(feature = "std")]
+pub fn solve_with(lines: &[String], matcher: &DigitMatcher) -> u64 {
+    solve_lines(lines, |line| get_coord_with(line, matcher)).sum
+}
+
+/// The configuration of a solver: the puzzle part along with the `DigitMatcher` used to read spelled digits in Part 2,
+/// and an optional hook preprocessing each line before it is scanned. It is put together with
+/// `SolverConfig::builder()`, so new options can be added without breaking callers.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct SolverConfig {
+    part: Part,
+    matcher: DigitMatcher,
+    preprocess: Option<fn(&str) -> String>,
+}
+
+#[cfg(feature = "std")]
+impl SolverConfig {
+    /// The function `builder` starts configuring a solver, by default for Part 2 with the English matcher.
+    pub fn builder() -> SolverConfigBuilder {
+        SolverConfigBuilder::default()
+    }
+
+    /// The function `part` returns the puzzle part the solver follows.
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    /// The function `matcher` returns the `DigitMatcher` the solver reads spelled digits with.
+    pub fn matcher(&self) -> &DigitMatcher {
+        &self.matcher
+    }
+
+    /// The function `coord` computes the coordinate of a line with the configured part and matcher, after running the
+    /// preprocessing hook on it when one is set.
+    ///
+    /// Arguments:
+    ///
+    /// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+    ///
+    /// Returns:
+    ///
+    /// The function `coord` returns an `Option<u32>`, which is `None` when the line contains no digits at all.
+    pub fn coord(&self, line: &str) -> Option<u32> {
+        match self.preprocess {
+            Some(preprocess) => self.scan(&preprocess(line)),
+            None => self.scan(line),
+        }
+    }
+
+    /// The function `scan` computes the coordinate of an already preprocessed line with the configured part and
+    /// matcher.
+    fn scan(&self, line: &str) -> Option<u32> {
+        match self.part {
+            Part::One => get_coord_for(line, Part::One),
+            Part::Two => get_coord_with(line, &self.matcher),
+        }
+    }
+
+    /// The function `solve` sums the coordinates of the lines with the configured part and matcher, skipping lines
+    /// that contain no digits.
+    ///
+    /// Arguments:
+    ///
+    /// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+    ///
+    /// Returns:
+    ///
+    /// The function `solve` returns the sum of all coordinates as a `u64`.
+    pub fn solve(&self, lines: &[String]) -> u64 {
+        self.solve_with_stats(lines).sum
+    }
+
+    /// The function `solve_with_stats` sums the coordinates of the lines like `solve` does, and also counts the lines
+    /// that produced a coordinate and the ones skipped because they contain no digits.
+    ///
+    /// Arguments:
+    ///
+    /// * `lines`: The `lines` parameter is a slice of `String`s, one per line of puzzle input.
+    ///
+    /// Returns:
+    ///
+    /// The function `solve_with_stats` returns a `SolveStats` with the sum and the line counts.
+    pub fn solve_with_stats(&self, lines: &[String]) -> SolveStats {
+        solve_lines(lines, |line| self.coord(line))
+    }
+
+    /// The function `solve_reader_with_stats` streams the lines of a buffered reader like `solve_reader` does and
+    /// gathers the statistics of `solve_with_stats` with the configured part and matcher, without holding the whole
+    /// input in memory.
+    ///
+    /// Arguments:
+    ///
+    /// * `reader`: The `reader` parameter is any `BufRead` source of puzzle input, such as the one `open_input`
+    ///   returns.
+    ///
+    /// Returns:
+    ///
+    /// The function `solve_reader_with_stats` returns a `Result<SolveStats, io::Error>`: the sum and the line counts,
+    /// or the first I/O error raised while reading.
+    pub fn solve_reader_with_stats<R: BufRead>(&self, reader: R) -> Result<SolveStats, io::Error> {
+        solve_lines_from(reader, |line| self.coord(line))
+    }
+}
+
+/// The builder of a `SolverConfig`. Case sensitivity, longest-match resolution and tie-breaking are applied to the
+/// matcher when building, whichever order the setters are called in, and only when they were set explicitly.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct SolverConfigBuilder {
+    part: Part,
+    matcher: DigitMatcher,
+    case_insensitive: Option<bool>,
+    longest_word_wins: Option<bool>,
+    prefer_words: Option<bool>,
+    preprocess: Option<fn(&str) -> String>,
+}
+
+#[cfg(feature = "std")]
+impl SolverConfigBuilder {
+    /// The function `part` selects the puzzle part whose rules are used to find the digits.
+    pub fn part(mut self, part: Part) -> SolverConfigBuilder {
+        self.part = part;
+        self
+    }
+
+    /// The function `matcher` selects the `DigitMatcher` providing the spelled digits in Part 2.
+    pub fn matcher(mut self, matcher: DigitMatcher) -> SolverConfigBuilder {
+        self.matcher = matcher;
+        self
+    }
+
+    /// The function `case_insensitive` makes the matcher ignore ASCII case, see `DigitMatcher::case_insensitive`.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> SolverConfigBuilder {
+        self.case_insensitive = Some(case_insensitive);
+        self
+    }
+
+    /// The function `longest_word_wins` makes only the longest word at a position count, see
+    /// `DigitMatcher::longest_word_wins`.
+    pub fn longest_word_wins(mut self, longest_word_wins: bool) -> SolverConfigBuilder {
+        self.longest_word_wins = Some(longest_word_wins);
+        self
+    }
+
+    /// The function `prefer_words` makes a word beat a numeral starting at the same index, see
+    /// `DigitMatcher::prefer_words`.
+    pub fn prefer_words(mut self, prefer_words: bool) -> SolverConfigBuilder {
+        self.prefer_words = Some(prefer_words);
+        self
+    }
+
+    /// The function `preprocess` sets a hook rewriting each line before digits are looked up in it, for example to
+    /// remove HTML entities such as `&amp;` whose letters could otherwise complete a spelled digit. By default lines
+    /// are scanned unchanged.
+    pub fn preprocess(mut self, preprocess: fn(&str) -> String) -> SolverConfigBuilder {
+        self.preprocess = Some(preprocess);
+        self
+    }
+
+    /// The function `build` finishes the configuration and returns the `SolverConfig`.
+    pub fn build(self) -> SolverConfig {
+        let mut matcher = self.matcher;
+        if let Some(case_insensitive) = self.case_insensitive {
+            matcher = matcher.case_insensitive(case_insensitive);
+        }
+        if let Some(longest_word_wins) = self.longest_word_wins {
+            matcher = matcher.longest_word_wins(longest_word_wins);
+        }
+        if let Some(prefer_words) = self.prefer_words {
+            matcher = matcher.prefer_words(prefer_words);
+        }
+
+        SolverConfig {
+            part: self.part,
+            matcher,
+            preprocess: self.preprocess,
+        }
+    }
+}
+
+/// The function `solve_lines` sums the coordinates produced by `coord` for each line and counts the lines that did or
+/// didn't produce one, in parallel when the `rayon` feature is enabled.
+#[cfg(feature = "std")]
+fn solve_lines<F>(lines: &[String], coord: F) -> SolveStats
+where
+    F: Fn(&str) -> Option<u32> + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    let lines = lines.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let lines = lines.iter();
+
+    log_stats(
+        lines
+            .map(|line| SolveStats::from(log_coord(line, coord(line))))
+            .sum(),
+    )
+}
+
+/// The function `strip_bom` removes the UTF-8 byte-order mark (`\u{FEFF}`) some editors save at the start of a file,
+/// so that it doesn't end up glued to the first line: unlike whitespace, trimming doesn't remove it.
+fn strip_bom(content: &str) -> &str {
+    content.strip_prefix('\u{FEFF}').unwrap_or(content)
+}
+
+/// The function `solve_str` splits an in-memory input into lines, trims them like `get_input` does, and sums their
+/// coordinates. It touches neither the filesystem nor the environment, which makes it suitable as an entry point when
+/// compiling to `wasm32-unknown-unknown`.
+///
+/// Arguments:
+///
+/// * `input`: The `input` parameter is a string slice (`&str`) holding the whole puzzle input.
+///
+/// Returns:
+///
+/// The function `solve_str` returns the sum of all coordinates as a `u64`.
+pub fn solve_str(input: &str) -> u64 {
+    solve_iter(strip_bom(input).lines().map(str::trim))
+}
+
+/// The function `solve_reader` reads lines one at a time from a buffered reader, trims them like `get_input` does, and
+/// accumulates the sum of their coordinates without holding the whole input in memory.
+///
+/// Arguments:
+///
+/// * `reader`: The `reader` parameter is any `BufRead` source of puzzle input, such as a `BufReader<File>`.
+///
+/// Returns:
+///
+/// The function `solve_reader` returns a `Result<u64, io::Error>`: the sum of all coordinates, or the first I/O error
+/// raised while reading.
+#[cfg(feature = "std")]
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<u64, io::Error> {
+    solve_lines_from(reader, get_coord).map(|stats| stats.sum)
+}
+
+/// The function `solve_lines_from` reads lines one at a time from a buffered reader, strips a leading byte-order mark
+/// and trims them like `get_input` does, then sums the coordinates produced by `coord` and counts the lines that did
+/// or didn't produce one, without holding the whole input in memory.
+#[cfg(feature = "std")]
+fn solve_lines_from<R, F>(reader: R, coord: F) -> Result<SolveStats, io::Error>
+where
+    R: BufRead,
+    F: Fn(&str) -> Option<u32>,
+{
+    let mut stats = SolveStats::default();
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = if idx == 0 { strip_bom(&line) } else { &line };
+        let line = line.trim();
+        stats = stats + SolveStats::from(log_coord(line, coord(line)));
+    }
+
+    Ok(log_stats(stats))
+}
+
+/// The function `solve_dir` solves every `*.txt` file of a directory with `get_input` and `solve`, in parallel when the
+/// `rayon` feature is enabled. Subdirectories and files with other extensions are skipped.
+///
+/// Arguments:
+///
+/// * `dir`: The `dir` parameter is any path-like value naming the directory holding the input files.
+///
+/// Returns:
+///
+/// The function `solve_dir` returns an `io::Result<Vec<(PathBuf, u64)>>` pairing each file with its sum, sorted by
+/// path, or the first I/O error raised while listing the directory or reading a file.
+#[cfg(feature = "std")]
+pub fn solve_dir<P: AsRef<Path>>(dir: P) -> io::Result<Vec<(PathBuf, u64)>> {
+    solve_dir_with(dir, |path| get_input(path), solve)
+}
+
+/// The function `solve_dir_with` is the variation of `solve_dir` reading each `*.txt` file of a directory with `read`
+/// and solving its lines with `solve`, for instance `get_input_lossy` and `SolverConfig::solve_with_stats`.
+///
+/// Arguments:
+///
+/// * `dir`: The `dir` parameter is any path-like value naming the directory holding the input files.
+/// * `read`: The `read` parameter reads the lines of a file, like `get_input` does.
+/// * `solve`: The `solve` parameter computes the result of a file from its lines, like `solve` does.
+///
+/// Returns:
+///
+/// The function `solve_dir_with` returns an `io::Result<Vec<(PathBuf, T)>>` pairing each file with its result, sorted
+/// by path, or the first I/O error raised while listing the directory or reading a file.
+#[cfg(feature = "std")]
+pub fn solve_dir_with<P, R, S, T>(dir: P, read: R, solve: S) -> io::Result<Vec<(PathBuf, T)>>
+where
+    P: AsRef<Path>,
+    R: Fn(&Path) -> io::Result<Vec<String>> + Sync + Send,
+    S: Fn(&[String]) -> T + Sync + Send,
+    T: Send,
+{
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+    paths.retain(|path| {
+        path.is_file() && path.extension().is_some_and(|extension| extension == "txt")
+    });
+    paths.sort();
+
+    #[cfg(feature = "rayon")]
+    let paths = paths.into_par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let paths = paths.into_iter();
+
+    paths
+        .map(|path| {
+            let result = solve(&read(&path)?);
+            Ok((path, result))
+        })
+        .collect()
+}
+
+/// The `get_input` function reads the contents of a file at the given path and returns them as a vector of strings, with
+/// leading and trailing whitespace removed from each line. With the `flate2` feature enabled, paths ending in `.gz` are
+/// decompressed while reading, and with the `reqwest` feature enabled, paths starting with `http://` or `https://` are
+/// downloaded instead of being read from disk.
+///
+/// Arguments:
+///
+/// * `path`: The `path` parameter in the `get_input` function is any path-like value (`&str`, `&Path`, `PathBuf`, ...)
+///   that represents the file path from which you want to read the input.
+///
+/// Returns:
+///
+/// The function `get_input` returns a `Result<Vec<String>, io::Error>`: the trimmed lines on success, or the I/O error
+/// raised while reading the file.
+#[cfg(feature = "std")]
+pub fn get_input<P: AsRef<Path>>(path: P) -> Result<Vec<String>, io::Error> {
+    get_input_with(path, char::is_whitespace)
+}
+
+/// The `get_input_with` function reads a file like `get_input`, but trims the characters selected by `trim` from both
+/// ends of each line instead of whitespace, so `|c| c == '"'` turns `"1a2"` wrapped in quotes into `1a2`.
+///
+/// Arguments:
+///
+/// * `path`: The `path` parameter is any path-like value that represents the file path to read the input from.
+/// * `trim`: The `trim` parameter is a predicate telling whether a character should be trimmed.
+///
+/// Returns:
+///
+/// The function `get_input_with` returns a `Result<Vec<String>, io::Error>`: the trimmed lines on success, or the I/O
+/// error raised while reading the file.
+#[cfg(feature = "std")]
+pub fn get_input_with<P, F>(path: P, trim: F) -> Result<Vec<String>, io::Error>
+where
+    P: AsRef<Path>,
+    F: Fn(char) -> bool,
+{
+    let content = String::from_utf8(read_input_bytes(path.as_ref())?)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    Ok(parse_lines_with(&content, trim))
+}
+
+/// The function `open_input` opens the input named by `path` for reading line by line, accepting the same sources as
+/// `get_input`: an `http://` or `https://` URL is downloaded with the `reqwest` feature, a `.gz` file is decompressed
+/// with the `flate2` feature, and anything else is read as a plain file. Nothing is read up front, so the result can be
+/// handed to `solve_reader` to stream inputs too large to hold in memory.
+///
+/// Arguments:
+///
+/// * `path`: The `path` parameter is any path-like value that represents the file path or URL to read the input from.
+///
+/// Returns:
+///
+/// The function `open_input` returns a `Result<Box<dyn BufRead>, io::Error>`: a buffered reader over the input, or
+/// the I/O error raised while opening it.
+#[cfg(feature = "std")]
+pub fn open_input<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, io::Error> {
+    let path = path.as_ref();
+
+    #[cfg(feature = "reqwest")]
+    if let Some(url) = path
+        .to_str()
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+    {
+        let response = reqwest::blocking::get(url)
+            .and_then(|response| response.error_for_status())
+            .map_err(io::Error::other)?;
+        return Ok(Box::new(io::BufReader::new(response)));
+    }
+
+    #[cfg(feature = "flate2")]
+    if path.extension().is_some_and(|extension| extension == "gz") {
+        let decoder = flate2::read::GzDecoder::new(fs::File::open(path)?);
+        return Ok(Box::new(io::BufReader::new(decoder)));
+    }
+
+    Ok(Box::new(io::BufReader::new(fs::File::open(path)?)))
+}
+
+/// The function `read_input_bytes` reads the raw bytes of the input named by `path` through `open_input`, shared by
+/// `get_input_with` and `get_input_lossy` so both accept the same sources.
+#[cfg(feature = "std")]
+fn read_input_bytes(path: &Path) -> Result<Vec<u8>, io::Error> {
+    let mut bytes = Vec::new();
+    io::Read::read_to_end(&mut open_input(path)?, &mut bytes)?;
+
+    Ok(bytes)
+}
+
+/// The `get_input_lossy` function reads a file, `.gz` archive or URL like `get_input`, but doesn't fail on invalid
+/// UTF-8: each invalid byte sequence is replaced with `U+FFFD REPLACEMENT CHARACTER`, so the digits on the rest of the
+/// line are still found. It is opt-in, since it silently alters the data it can't decode.
+///
+/// Arguments:
+///
+/// * `path`: The `path` parameter is any path-like value that represents the file path to read the input from.
+///
+/// Returns:
+///
+/// The function `get_input_lossy` returns a `Result<Vec<String>, io::Error>`: the trimmed lines on success, or the I/O
+/// error raised while reading the file.
+#[cfg(feature = "std")]
+pub fn get_input_lossy<P: AsRef<Path>>(path: P) -> Result<Vec<String>, io::Error> {
+    let bytes = read_input_bytes(path.as_ref())?;
+
+    Ok(parse_lines(&String::from_utf8_lossy(&bytes)))
+}
+
+/// The `parse_lines` function splits raw input into lines and removes leading and trailing whitespace from each of them.
+/// It is shared by every input source, so files and standard input are trimmed the same way. Both `\n` and `\r\n` line
+/// endings are accepted, a stray `\r` is removed by the trimming, and a final line without a trailing newline is kept,
+/// so `"1a2\r\n3b4\r\n"` yields `["1a2", "3b4"]`. A leading UTF-8 byte-order mark is skipped as well. Trimming removes
+/// every Unicode whitespace character, including tabs and non-breaking spaces (`\u{00A0}`), but only at the ends of a
+/// line: `"\u{00A0}one2"` becomes `"one2"`, so the digits after it are still found, while whitespace inside a line is
+/// kept as is. Digits are then looked up in the trimmed line, so their byte indices never refer to the removed text.
+///
+/// Arguments:
+///
+/// * `content`: The `content` parameter is a string slice (`&str`) holding the whole input.
+///
+/// Returns:
+///
+/// The function `parse_lines` returns a `Vec<String>` with one trimmed string per line.
+#[cfg(feature = "std")]
+pub fn parse_lines(content: &str) -> Vec<String> {
+    parse_lines_with(content, char::is_whitespace)
+}
+
+/// The `parse_lines_with` function splits raw input into lines like `parse_lines`, but trims the characters selected by
+/// `trim` from both ends of each line instead of whitespace.
+///
+/// Arguments:
+///
+/// * `content`: The `content` parameter is a string slice (`&str`) holding the whole input.
+/// * `trim`: The `trim` parameter is a predicate telling whether a character should be trimmed.
+///
+/// Returns:
+///
+/// The function `parse_lines_with` returns a `Vec<String>` with one trimmed string per line.
+#[cfg(feature = "std")]
+pub fn parse_lines_with<F>(content: &str, trim: F) -> Vec<String>
+where
+    F: Fn(char) -> bool,
+{
+    strip_bom(content)
+        .lines()
+        .map(|s| -> String { s.trim_matches(&trim).to_string() })
+        .collect()
+}
+
+/// The tokens `generate_input` assembles lines from: numerals, spelled digits (including overlapping ones) and noise.
+#[cfg(feature = "test-util")]
+const TOKENS: &[&str] = &[
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine", "oneight", "twone", "eightwo", "x", "q", "pl", "zk", "abc", "mnr",
+];
+
+/// The function `generate_input` deterministically builds a synthetic puzzle input mixing numeric digits, spelled
+/// digits, overlapping words and noise letters, using a small linear congruential generator, so that benchmarks and
+/// tests can rely on a reproducible input: the same seed always yields the same text.
+///
+/// Arguments:
+///
+/// * `lines`: The `lines` parameter is the number of lines to generate.
+/// * `seed`: The `seed` parameter is the initial state of the generator.
+///
+/// Returns:
+///
+/// The function `generate_input` returns a `String` holding the lines, each terminated by `\n`.
+#[cfg(feature = "test-util")]
+pub fn generate_input(lines: usize, seed: u64) -> String {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+
+    let mut input = String::new();
+    for _ in 0..lines {
+        let length = 4 + next() % 8;
+        for _ in 0..length {
+            input.push_str(TOKENS[next() % TOKENS.len()]);
+        }
+        input.push('\n');
+    }
+
+    input
+}
@@ -1,263 +1,769 @@
-use std::{fs, ops::Add};
+use std::{
+    env,
+    io::{self, IsTerminal, Read},
+    ops::Range,
+    path::Path,
+    process::ExitCode,
+    time::Instant,
+};
 
-/// The `const DIGITS: &[&str] = &[...]` line is defining a constant array of string slices. Each string slice represents a
-/// digit from one to nine. This array is used in various functions to match and convert alphabetic digits to their
-/// corresponding numeric values.
-const DIGITS: &[&str] = &[
-    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-];
+use aoc_2023_day1::{
+    find_first_digit_span, find_last_digit_span, get_input, get_input_lossy, open_input,
+    parse_lines, solve_dir_with, DigitMatcher, Part, SolveStats, SolverConfig,
+};
 
-/// The function `find_first_alpha_digit` takes a string as input and returns the first occurrence of a substring that
-/// consists of either alphabetic characters or digits, along with its index in the original string.
-///
-/// Arguments:
-///
-/// * `str`: The `str` parameter is a reference to a string (`&str`). It represents the input string in which we want to
-/// find the first occurrence of a substring that consists of either an alphabetic character or a digit.
-///
-/// Returns:
-///
-/// The function `find_first_alpha_digit` returns an `Option` containing a tuple `(String, usize)`.
-fn find_first_alpha_digit(str: &str) -> Option<(String, usize)> {
-    DIGITS
-        .iter()
-        .filter_map(|&substring| {
-            str.find(substring)
-                .map(|index| (substring.to_string(), index))
-        })
-        .min_by_key(|&(_, index)| index)
+/// The input file read when no path is given on the command line.
+const DEFAULT_INPUT_PATH: &str = "res/data.txt";
+
+/// The environment variable that overrides the input file when no path is given on the command line.
+const INPUT_PATH_VAR: &str = "AOC_DAY1_INPUT";
+
+/// The environment variable selecting the puzzle part when `--part` isn't given on the command line.
+const PART_VAR: &str = "AOC_DAY1_PART";
+
+/// The environment variable selecting the level of the library's log messages printed to stderr, when built with the
+/// `log` feature.
+#[cfg(feature = "log")]
+const LOG_VAR: &str = "AOC_DAY1_LOG";
+
+/// The usage message printed by `--help`.
+const USAGE: &str = "\
+Usage: day1 [OPTIONS] [PATH...]
+
+Reads the input from, in order of precedence: the PATHs, the file named by the AOC_DAY1_INPUT environment variable,
+standard input when it is piped, and finally res/data.txt. With several PATHs, the sum covers all of them and a
+subtotal is printed for each file; unreadable files are reported and skipped unless --strict is given. When built
+with the reqwest feature, a PATH may also be an http:// or https:// URL to download. When built with the log
+feature, the AOC_DAY1_LOG environment variable (off, error, warn, info, debug or trace) selects the library's log
+messages printed to stderr, such as the coordinate of each line at the debug level.
+
+Options:
+  --part 1|2             Select the puzzle part (default: the AOC_DAY1_PART environment variable, then 2)
+  --lang en|fr|de        Select the language of spelled digits in part 2 (default: en)
+  --verbose              Print each line's coordinate before the sum
+  --color auto|always|never
+                         Highlight the first and last digit of each line in verbose mode (default: auto, which
+                         only colors a terminal)
+  --format human|json|csv
+                         Select the output format (default: human); csv lists each line with its coordinate
+  --fail-fast            Fail on the first line that has no digits, without checking the lines after it
+  --lossy                Replace invalid UTF-8 in the input with U+FFFD instead of failing to read it
+  --strict               Fail, listing every line that has no digits, instead of skipping them
+  --count                Only print how many lines there are and how many contain digits
+  --dir DIR              Only print the sum of every *.txt file in DIR, then their total; can't be combined with
+                         the options needing the lines (--verbose, --strict, --fail-fast, --count, --diff,
+                         --stats and --format csv)
+  --diff OTHER           Only print the lines whose coordinate differs in the file OTHER, paired by line number
+  --quiet, --sum-only    Print only the bare sum, without any other output
+  --time                 Print how long summing the lines took to stderr
+  --stats                Also print the minimum, maximum and mean coordinate after the sum (human format)
+  --help                 Print this message";
+
+/// The marker printed in verbose mode in place of the coordinate of a line without digits.
+const NO_COORD_MARKER: &str = "-";
+
+/// The marker printed by `--diff` in place of the coordinate of a line that only one of the files has.
+const MISSING_LINE_MARKER: &str = "missing";
+
+/// The ANSI escape codes used to highlight the first and last digit of a line in verbose mode.
+const FIRST_DIGIT_COLOR: &str = "1;32";
+const LAST_DIGIT_COLOR: &str = "1;36";
+
+/// When verbose mode highlights digits with ANSI colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// The formats the final result can be printed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Format {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+/// Where a part of the input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Input {
+    File(String),
+    Stdin,
+}
+
+impl Input {
+    /// The function `name` returns how the input is referred to in messages and subtotals.
+    fn name(&self) -> &str {
+        match self {
+            Input::File(path) => path,
+            Input::Stdin => "stdin",
+        }
+    }
 }
 
-/// The function `find_last_alpha_digit` finds the last occurrence of a substring consisting of only alphabetic characters
-/// and digits in a given string and returns the substring along with its index.
+/// The logger printing the library's log messages to stderr, prefixed with their level.
+#[cfg(feature = "log")]
+struct StderrLogger;
+
+#[cfg(feature = "log")]
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// The function `init_logger` installs the `StderrLogger` with the level named by the `AOC_DAY1_LOG` environment
+/// variable.
 ///
 /// Arguments:
 ///
-/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
-/// search for the last occurrence of a substring that consists of both alphabetic characters and digits.
+/// * `value`: The `value` parameter is the value of the `AOC_DAY1_LOG` environment variable.
 ///
 /// Returns:
 ///
-/// The function `find_last_alpha_digit` returns an `Option<(String, usize)>`.
-fn find_last_alpha_digit(str: &str) -> Option<(String, usize)> {
-    let mut last_index = None;
-    let mut last_substring = None;
-
-    for &substring in DIGITS {
-        if let Some(index) = str.rfind(substring) {
-            if last_index.map_or(true, |last| index > last) {
-                last_index = Some(index);
-                last_substring = Some((substring.to_string(), index));
+/// The function `init_logger` returns a `Result<(), String>`, with a message describing the problem when the level is
+/// invalid.
+#[cfg(feature = "log")]
+fn init_logger(value: &str) -> Result<(), String> {
+    let level: log::LevelFilter = value.parse().map_err(|_| {
+        format!(
+            "Invalid log level '{}' in {}, expected off, error, warn, info, debug or trace",
+            value, LOG_VAR
+        )
+    })?;
+    log::set_logger(&StderrLogger).map_err(|err| err.to_string())?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+/// The options the binary accepts on the command line.
+#[derive(Debug, Default)]
+struct Options {
+    paths: Vec<String>,
+    config: SolverConfig,
+    verbose: bool,
+    color: ColorChoice,
+    format: Format,
+    strict: bool,
+    fail_fast: bool,
+    lossy: bool,
+    count: bool,
+    diff: Option<String>,
+    dir: Option<String>,
+    quiet: bool,
+    time: bool,
+    stats: bool,
+    /// Whether the configured matcher is the English one, whose digit spans `digit_spans` can locate.
+    english_spans: bool,
+}
+
+impl Options {
+    /// The function `read` reads the lines of a file, replacing invalid UTF-8 when `--lossy` is given.
+    fn read<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<String>> {
+        if self.lossy {
+            get_input_lossy(path)
+        } else {
+            get_input(path)
+        }
+    }
+
+    /// The function `read_input` reads all the lines of an input, replacing invalid UTF-8 when `--lossy` is given.
+    fn read_input(&self, input: &Input) -> io::Result<Vec<String>> {
+        match input {
+            Input::File(path) => self.read(path),
+            Input::Stdin if self.lossy => {
+                let mut bytes = Vec::new();
+                io::stdin().read_to_end(&mut bytes)?;
+                Ok(parse_lines(&String::from_utf8_lossy(&bytes)))
             }
+            Input::Stdin => io::read_to_string(io::stdin()).map(|content| parse_lines(&content)),
+        }
+    }
+
+    /// The function `streams` tells whether only the sum is needed, so that the inputs can be streamed line by line
+    /// instead of being held in memory: no option needs the lines themselves, and `--lossy`, which decodes whole
+    /// inputs, isn't given.
+    fn streams(&self) -> bool {
+        !self.verbose
+            && !self.count
+            && self.diff.is_none()
+            && !self.strict
+            && !self.fail_fast
+            && !self.lossy
+            && !self.stats
+            && self.format == Format::Human
+    }
+
+    /// The function `stream_input` sums an input line by line with the selected part and language, without holding
+    /// its lines in memory.
+    fn stream_input(&self, input: &Input) -> io::Result<SolveStats> {
+        match input {
+            Input::File(path) => self.config.solve_reader_with_stats(open_input(path)?),
+            Input::Stdin => self.config.solve_reader_with_stats(io::stdin().lock()),
         }
     }
 
-    last_substring
+    /// The function `coord` computes the coordinate of a line with the selected part and language.
+    fn coord(&self, line: &str) -> Option<u32> {
+        self.config.coord(line)
+    }
+
+    /// The function `solve` sums the coordinates of the lines with the selected part and language, counting the lines
+    /// that did or didn't produce one.
+    fn solve(&self, lines: &[String]) -> SolveStats {
+        self.config.solve_with_stats(lines)
+    }
+
+    /// The function `digit_spans` returns the byte ranges of the first and last digit of a line, or `None` when the
+    /// line has no digits or its spans can't be located because a language other than English is selected.
+    fn digit_spans(&self, line: &str) -> Option<(Range<usize>, Range<usize>)> {
+        match self.config.part() {
+            Part::One => {
+                let mut numerals = line
+                    .char_indices()
+                    .filter(|(_, char)| char.is_ascii_digit())
+                    .map(|(idx, _)| idx..idx + 1);
+                let first = numerals.next()?;
+                let last = numerals.next_back().unwrap_or_else(|| first.clone());
+                Some((first, last))
+            }
+            Part::Two if self.english_spans => {
+                let (_, first) = find_first_digit_span(line)?;
+                let (_, last) = find_last_digit_span(line)?;
+                Some((first, last))
+            }
+            Part::Two => None,
+        }
+    }
 }
 
-/// The function `alpha_to_numeric` takes a string representing an alphabetic digit and returns the corresponding numeric
-/// digit, or `None` if the input is not a valid alphabetic digit.
-///
-/// Arguments:
-///
-/// * `alpha_digit`: The `alpha_digit` parameter is a reference to a `String` that represents an alphabetic digit.
-///
-/// Returns:
-///
-/// The function `alpha_to_numeric` returns an `Option<String>`.
-fn alpha_to_numeric(alpha_digit: &String) -> Option<String> {
-    DIGITS
-        .iter()
-        .position(|&digit| digit.eq(alpha_digit))
-        .map(|result| (result as u8 + 1).to_string())
+/// The function `print_stats` prints the minimum, maximum and mean of the coordinates, or the `NO_COORD_MARKER` for
+/// each of them when there are no coordinates at all.
+fn print_stats<I: Iterator<Item = u32>>(coords: I) {
+    let (count, total, min, max) = coords.fold((0u64, 0u64, u32::MAX, u32::MIN), |acc, coord| {
+        (
+            acc.0 + 1,
+            acc.1 + u64::from(coord),
+            acc.2.min(coord),
+            acc.3.max(coord),
+        )
+    });
+
+    if count == 0 {
+        println!(
+            "min={} max={} mean={}",
+            NO_COORD_MARKER, NO_COORD_MARKER, NO_COORD_MARKER
+        );
+    } else {
+        println!(
+            "min={} max={} mean={:.2}",
+            min,
+            max,
+            total as f64 / count as f64
+        );
+    }
 }
 
-/// The function `find_numeric_digit` takes an iterator of characters and returns the first numeric digit along with its
-/// index, if found.
-/// 
-/// Arguments:
-/// 
-/// * `iter`: The `iter` parameter is an iterator that yields characters (`char`).
-/// 
-/// Returns:
-/// 
-/// The function `find_numeric_digit` returns an `Option` containing a tuple `(String, usize)`.
-fn find_numeric_digit<I>(iter: I) -> Option<(String, usize)>
-where
-    I: IntoIterator<Item = char>,
-{
-    iter.into_iter()
-        .enumerate()
-        .find(|(_, c)| c.is_numeric())
-        .map(|(idx, char)| (char.to_string(), idx))
+/// The function `print_sum` prints the subtotal of each input, if any, followed by the sum along with the number of
+/// lines counted and skipped.
+fn print_sum(subtotals: &[(&str, u64)], stats: &SolveStats) {
+    for (source, subtotal) in subtotals {
+        println!("{}: {}", source, subtotal);
+    }
+    println!(
+        "Sum is {} (counted {}, skipped {})",
+        stats.sum, stats.counted, stats.skipped
+    );
 }
 
-/// The function `rev_find_numeric_digit` takes an iterator of characters, collects them into a vector, and then finds the
-/// last numeric digit along with its index.
-///
-/// Arguments:
-///
-/// * `iter`: The `iter` parameter is an iterator that yields characters (`char`).
-///
-/// Returns:
-///
-/// The function `rev_find_numeric_digit` returns an `Option` containing a tuple `(String, usize)`.
-fn rev_find_numeric_digit<I>(iter: I) -> Option<(String, usize)>
-where
-    I: IntoIterator<Item = char>,
-    I::IntoIter: DoubleEndedIterator<Item = char>,
-{
-    iter.into_iter()
-        .collect::<Vec<char>>()
-        .into_iter()
-        .enumerate()
-        .rev()
-        .find(|(_, c)| c.is_numeric())
-        .map(|(idx, char)| (char.to_string(), idx))
+/// The function `csv_field` escapes text for a CSV cell as RFC 4180 describes: text containing a comma, a double quote
+/// or a line break is wrapped in double quotes, with its own double quotes doubled.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
 }
 
-/// The function `find_first_numeric_digit` takes a string as input and returns the first numeric digit found along with its
-/// index, if any.
-///
-/// Arguments:
-///
-/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
-/// search for the first numeric digit.
-fn find_first_numeric_digit(str: &str) -> Option<(String, usize)> {
-    find_numeric_digit(str.chars())
+/// The function `paint` wraps text in the ANSI escape sequences selecting the given color, then resetting it.
+fn paint(text: &str, color: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", color, text)
 }
 
-/// The function `find_last_numeric_digit` takes a string as input and returns the last numeric digit found in the string
-/// along with its index, if any.
-///
-/// Arguments:
-///
-/// * `str`: The `str` parameter is a reference to a string slice (`&str`). It represents the input string that we want to
-/// search for the last numeric digit.
-fn find_last_numeric_digit(str: &str) -> Option<(String, usize)> {
-    rev_find_numeric_digit(str.chars())
+/// The function `highlight` colors the first and last digit of a line. When both spans overlap, as the words of
+/// "oneight" do, or are the same single digit, their union is colored as the first digit.
+fn highlight(line: &str, first: Range<usize>, last: Range<usize>) -> String {
+    if first.end <= last.start {
+        format!(
+            "{}{}{}{}{}",
+            &line[..first.start],
+            paint(&line[first.clone()], FIRST_DIGIT_COLOR),
+            &line[first.end..last.start],
+            paint(&line[last.clone()], LAST_DIGIT_COLOR),
+            &line[last.end..]
+        )
+    } else {
+        let start = first.start.min(last.start);
+        let end = first.end.max(last.end);
+        format!(
+            "{}{}{}",
+            &line[..start],
+            paint(&line[start..end], FIRST_DIGIT_COLOR),
+            &line[end..]
+        )
+    }
 }
 
-/// The function `get_first_digit` returns the first numeric or alphabetic digit found in a given string, prioritizing
-/// numeric digits over alphabetic digits.
-///
-/// Arguments:
-///
-/// * `line`: The `line` parameter is a string that represents a line of text.
-///
-/// Returns:
-///
-/// The function `get_first_digit` returns an `Option<String>`.
-fn get_first_digit(line: &str) -> Option<String> {
-    let first_numeric_digit = find_first_numeric_digit(line);
-    let first_alpha_digit = find_first_alpha_digit(line);
-
-    match (first_numeric_digit, first_alpha_digit) {
-        (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_idx))) => {
-            if numeric_idx < alpha_idx {
-                Some(numeric_digit)
-            } else {
-                alpha_to_numeric(&alpha_digit)
-            }
-        }
-        (Some((numeric_digit, _)), None) => Some(numeric_digit),
-        (None, Some((alpha_digit, _))) => alpha_to_numeric(&alpha_digit),
+/// The function `parse_part` reads the value of `--part` or of the `AOC_DAY1_PART` environment variable.
+fn parse_part(value: &str) -> Option<Part> {
+    match value {
+        "1" => Some(Part::One),
+        "2" => Some(Part::Two),
         _ => None,
     }
 }
 
-/// The function `get_last_digit` takes a string as input and returns the last numeric or alphabetic digit found in the
-/// string, converting alphabetic digits to their corresponding numeric values if necessary.
+/// The function `parse_args` turns the command-line arguments (without the program name) into `Options`.
 ///
 /// Arguments:
 ///
-/// * `line`: The `line` parameter is a string that represents a line of text.
+/// * `args`: The `args` parameter is an iterator over the command-line arguments.
+/// * `env_part`: The `env_part` parameter is the value of the `AOC_DAY1_PART` environment variable, if set. It selects
+///   the part unless `--part` is given.
 ///
 /// Returns:
 ///
-/// The function `get_last_digit` returns an `Option<String>`.
-fn get_last_digit(line: &str) -> Option<String> {
-    let last_numeric_digit = find_last_numeric_digit(line);
-    let last_alpha_digit = find_last_alpha_digit(line);
-
-    match (last_numeric_digit, last_alpha_digit) {
-        (Some((numeric_digit, numeric_idx)), Some((alpha_digit, alpha_idx))) => {
-            if numeric_idx > alpha_idx {
-                Some(numeric_digit)
-            } else {
-                alpha_to_numeric(&alpha_digit)
+/// The function `parse_args` returns a `Result<Options, String>`, with a message describing the problem when the
+/// arguments are invalid.
+fn parse_args<I>(args: I, env_part: Option<&str>) -> Result<Options, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut config = SolverConfig::builder();
+    let mut part = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = Some(match args.next() {
+                    Some(value) => parse_part(&value)
+                        .ok_or_else(|| format!("Invalid part '{}', expected 1 or 2", value))?,
+                    None => return Err("Missing value for --part".to_string()),
+                })
+            }
+            "--lang" => {
+                config = config.matcher(match args.next().as_deref() {
+                    Some("en") => DigitMatcher::english(),
+                    Some("fr") => DigitMatcher::french(),
+                    Some("de") => DigitMatcher::german(),
+                    Some(other) => {
+                        return Err(format!(
+                            "Invalid language '{}', expected en, fr or de",
+                            other
+                        ))
+                    }
+                    None => return Err("Missing value for --lang".to_string()),
+                })
+            }
+            "--verbose" => options.verbose = true,
+            "--color" => {
+                options.color = match args.next().as_deref() {
+                    Some("auto") => ColorChoice::Auto,
+                    Some("always") => ColorChoice::Always,
+                    Some("never") => ColorChoice::Never,
+                    Some(other) => {
+                        return Err(format!(
+                            "Invalid color choice '{}', expected auto, always or never",
+                            other
+                        ))
+                    }
+                    None => return Err("Missing value for --color".to_string()),
+                }
             }
+            "--strict" => options.strict = true,
+            "--fail-fast" => options.fail_fast = true,
+            "--lossy" => options.lossy = true,
+            "--count" => options.count = true,
+            "--dir" => match args.next() {
+                Some(dir) => options.dir = Some(dir),
+                None => return Err("Missing value for --dir".to_string()),
+            },
+            "--diff" => match args.next() {
+                Some(other) => options.diff = Some(other),
+                None => return Err("Missing value for --diff".to_string()),
+            },
+            "--quiet" | "--sum-only" => options.quiet = true,
+            "--time" => options.time = true,
+            "--stats" => options.stats = true,
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("human") => Format::Human,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    Some(other) => {
+                        return Err(format!(
+                            "Invalid format '{}', expected human, json or csv",
+                            other
+                        ))
+                    }
+                    None => return Err("Missing value for --format".to_string()),
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unexpected argument '{}'", arg)),
+            _ => options.paths.push(arg),
         }
-        (Some((numeric_digit, _)), None) => Some(numeric_digit),
-        (None, Some((alpha_digit, _))) => alpha_to_numeric(&alpha_digit),
-        _ => None,
     }
+
+    // The environment variable is only consulted, and so only rejected when invalid, if `--part` wasn't given.
+    if let (None, Some(value)) = (part, env_part) {
+        let invalid = || format!("Invalid part '{}' in {}, expected 1 or 2", value, PART_VAR);
+        part = Some(parse_part(value).ok_or_else(invalid)?);
+    }
+    if let Some(part) = part {
+        config = config.part(part);
+    }
+    if options.dir.is_some()
+        && (options.verbose
+            || options.strict
+            || options.fail_fast
+            || options.count
+            || options.diff.is_some()
+            || options.stats
+            || options.format == Format::Csv)
+    {
+        return Err(
+            "--dir can't be combined with --verbose, --strict, --fail-fast, --count, --diff, --stats or --format csv"
+                .to_string(),
+        );
+    }
+    options.config = config.build();
+    options.english_spans = *options.config.matcher() == DigitMatcher::english();
+    Ok(options)
 }
 
-/// The function `get_coord` extracts the first and last digit from a given string, concatenates them, and converts the
-/// resulting string to an unsigned 32-bit integer.
+/// The function `stream` solves the inputs line by line, without holding their lines in memory, then prints the sum
+/// like the human format does. It is used when `Options::streams` tells that no option needs the lines themselves, so
+/// that inputs larger than the available memory can be solved. An input that can't be read is reported and skipped.
 ///
 /// Arguments:
 ///
-/// * `line`: The `line` parameter is a `String` that represents a line of text.
+/// * `options`: The `options` parameter holds the parsed command-line options.
+/// * `inputs`: The `inputs` parameter lists the inputs to solve, in order.
 ///
 /// Returns:
 ///
-/// The function `get_coord` returns a `u32` value.
-fn get_coord(line: String) -> u32 {
-    let mut coord = String::new();
+/// The function `stream` returns the `ExitCode` of the program, a failure when no input could be read.
+fn stream(options: &Options, inputs: &[Input]) -> ExitCode {
+    let start = Instant::now();
+    let mut subtotals = Vec::new();
+    for input in inputs {
+        match options.stream_input(input) {
+            Ok(stats) => {
+                if stats.counted + stats.skipped == 0 {
+                    eprintln!("No input lines found in {}", input.name());
+                }
+                subtotals.push((input.name(), stats));
+            }
+            Err(err) => eprintln!("Couldn't read input from {}: {}", input.name(), err),
+        }
+    }
 
-    if let Some(first_digit) = get_first_digit(&line) {
-        coord = coord.add(&first_digit);
+    if subtotals.is_empty() {
+        return ExitCode::FAILURE;
     }
 
-    if let Some(last_digit) = get_last_digit(&line) {
-        coord = coord.add(&last_digit);
+    let stats: SolveStats = subtotals.iter().map(|(_, stats)| *stats).sum();
+    if options.time {
+        eprintln!(
+            "Parsed {} lines in {:.1?}",
+            stats.counted + stats.skipped,
+            start.elapsed()
+        );
     }
 
-    assert!(coord.len() == 2);
+    if options.quiet {
+        println!("{}", stats.sum);
+    } else if inputs.len() > 1 {
+        let subtotals: Vec<(&str, u64)> = subtotals
+            .iter()
+            .map(|(source, stats)| (*source, stats.sum))
+            .collect();
+        print_sum(&subtotals, &stats);
+    } else {
+        print_sum(&[], &stats);
+    }
 
-    coord.parse::<u32>().unwrap()
+    ExitCode::SUCCESS
 }
 
-/// The `get_input` function reads the contents of a file at the given path and returns them as a vector of strings, with
-/// leading and trailing whitespace removed from each line.
-///
-/// Arguments:
-///
-/// * `path`: The `path` parameter in the `get_input` function is a `String` that represents the file path from which you
-/// want to read the input.
-///
-/// Returns:
-///
-/// The function `get_input` returns a `Vec<String>`, which is a vector of strings.
-fn get_input(path: String) -> Vec<String> {
-    let content = fs::read_to_string(path).expect("Couldn't read input");
-    let lines = content
-        .lines()
-        .map(|s| -> String { s.trim().to_string() })
-        .collect();
-
-    lines
-}
+/// The main function reads lines from one or more files, converts each line to a coordinate, and calculates the sum of
+/// all coordinates. The input is taken from the paths given on the command line, then from the `AOC_DAY1_INPUT`
+/// environment variable, then from piped standard input, falling back to `res/data.txt`. Errors are reported on stderr
+/// and turned into a non-zero exit code.
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    #[cfg(feature = "log")]
+    if let Ok(value) = env::var(LOG_VAR) {
+        if let Err(err) = init_logger(&value) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let env_part = env::var(PART_VAR).ok();
+    let options = match parse_args(args, env_part.as_deref()) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(dir) = &options.dir {
+        let start = Instant::now();
+        let results = solve_dir_with(dir, |path| options.read(path), |lines| options.solve(lines));
+        let results = match results {
+            Ok(results) => results,
+            Err(err) => {
+                eprintln!("Couldn't read input from {}: {}", dir, err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let stats: SolveStats = results.iter().map(|(_, stats)| *stats).sum();
+        if options.time {
+            eprintln!(
+                "Parsed {} lines in {:.1?}",
+                stats.counted + stats.skipped,
+                start.elapsed()
+            );
+        }
+
+        if options.quiet {
+            println!("{}", stats.sum);
+            return ExitCode::SUCCESS;
+        }
+
+        match options.format {
+            Format::Human => {
+                let sources: Vec<String> = results
+                    .iter()
+                    .map(|(path, _)| path.display().to_string())
+                    .collect();
+                let subtotals: Vec<(&str, u64)> = sources
+                    .iter()
+                    .zip(&results)
+                    .map(|(source, (_, stats))| (source.as_str(), stats.sum))
+                    .collect();
+                print_sum(&subtotals, &stats);
+            }
+            Format::Json => println!(
+                "{{\"sum\": {}, \"lines\": {}, \"skipped\": {}}}",
+                stats.sum,
+                stats.counted + stats.skipped,
+                stats.skipped
+            ),
+            Format::Csv => unreachable!("parse_args rejects --dir with --format csv"),
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let inputs = if !options.paths.is_empty() {
+        options.paths.iter().cloned().map(Input::File).collect()
+    } else if let Ok(path) = env::var(INPUT_PATH_VAR) {
+        vec![Input::File(path)]
+    } else if !io::stdin().is_terminal() {
+        vec![Input::Stdin]
+    } else {
+        vec![Input::File(DEFAULT_INPUT_PATH.to_string())]
+    };
+
+    if options.streams() {
+        return stream(&options, &inputs);
+    }
+
+    let input_count = inputs.len();
+    // The lines of every input are moved into `lines` rather than copied, each input keeping the range of its own.
+    let mut lines: Vec<String> = Vec::new();
+    let mut files = Vec::new();
+    for input in &inputs {
+        let source = input.name();
+        match options.read_input(input) {
+            Ok(input_lines) => {
+                if input_lines.is_empty() {
+                    eprintln!("No input lines found in {}", source);
+                }
+                let start = lines.len();
+                lines.extend(input_lines);
+                files.push((source, start..lines.len()));
+            }
+            Err(err) => eprintln!("Couldn't read input from {}: {}", source, err),
+        }
+    }
+
+    if files.is_empty() || (options.strict && files.len() < input_count) {
+        return ExitCode::FAILURE;
+    }
+
+    if options.count {
+        let with_digits = lines
+            .iter()
+            .filter(|line| options.coord(line).is_some())
+            .count();
+        println!("total={} with_digits={}", lines.len(), with_digits);
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(other) = &options.diff {
+        let other_lines = match options.read(other) {
+            Ok(other_lines) => other_lines,
+            Err(err) => {
+                eprintln!("Couldn't read input from {}: {}", other, err);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let show =
+            |coord: Option<u32>| coord.map_or(NO_COORD_MARKER.to_string(), |c| c.to_string());
+        for idx in 0..lines.len().max(other_lines.len()) {
+            let before = lines.get(idx).map(|line| show(options.coord(line)));
+            let after = other_lines.get(idx).map(|line| show(options.coord(line)));
+            if before != after {
+                println!(
+                    "line {}: {} -> {}",
+                    idx + 1,
+                    before.as_deref().unwrap_or(MISSING_LINE_MARKER),
+                    after.as_deref().unwrap_or(MISSING_LINE_MARKER)
+                );
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if options.fail_fast {
+        let invalid = files.iter().find_map(|(source, range)| {
+            let lines = &lines[range.clone()];
+            lines
+                .iter()
+                .position(|line| options.coord(line).is_none())
+                .map(|idx| (source, idx, &lines[idx]))
+        });
+
+        if let Some((source, idx, line)) = invalid {
+            if input_count > 1 {
+                eprint!("{}: ", source);
+            }
+            eprintln!("line {}: no digits in '{}'", idx + 1, line);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if options.strict {
+        let mut valid = true;
+        for (source, range) in &files {
+            for (idx, line) in lines[range.clone()].iter().enumerate() {
+                if options.coord(line).is_none() {
+                    if input_count > 1 {
+                        eprint!("{}: ", source);
+                    }
+                    eprintln!("line {}: no digits in '{}'", idx + 1, line);
+                    valid = false;
+                }
+            }
+        }
+
+        if !valid {
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if options.verbose && !options.quiet {
+        let color = match options.color {
+            ColorChoice::Auto => io::stdout().is_terminal(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        };
+
+        for line in &lines {
+            let shown = match options.digit_spans(line) {
+                Some((first, last)) if color => highlight(line, first, last),
+                _ => line.clone(),
+            };
+            match options.coord(line) {
+                Some(coord) => println!("{}\t{}", coord, shown),
+                None => println!("{}\t{}", NO_COORD_MARKER, shown),
+            }
+        }
+    }
+
+    let start = Instant::now();
+    let stats = options.solve(&lines);
+    if options.time {
+        eprintln!("Parsed {} lines in {:.1?}", lines.len(), start.elapsed());
+    }
+
+    if options.quiet {
+        println!("{}", stats.sum);
+        return ExitCode::SUCCESS;
+    }
+
+    match options.format {
+        Format::Human => {
+            let subtotals: Vec<(&str, u64)> = if input_count > 1 {
+                files
+                    .iter()
+                    .map(|(source, range)| (*source, options.solve(&lines[range.clone()]).sum))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            print_sum(&subtotals, &stats);
+            if options.stats {
+                print_stats(lines.iter().filter_map(|line| options.coord(line)));
+            }
+        }
+        Format::Json => {
+            println!(
+                "{{\"sum\": {}, \"lines\": {}, \"skipped\": {}}}",
+                stats.sum,
+                lines.len(),
+                stats.skipped
+            );
+        }
+        Format::Csv => {
+            println!("line,coord");
+            for line in &lines {
+                let coord = options.coord(line).map(|coord| coord.to_string());
+                println!("{},{}", csv_field(line), coord.unwrap_or_default());
+            }
+        }
+    }
+
+    // println!("{:?}", get_coord("two1nine"));
+    // println!("{:?}", get_coord("eightwothree"));
+    // println!("{:?}", get_coord("abcone2threexyz"));
+    // println!("{:?}", get_coord("xtwone3four"));
+    // println!("{:?}", get_coord("4nineeightseven2"));
+    // println!("{:?}", get_coord("zoneight234"));
+    // println!("{:?}", get_coord("7pqrstsixteen"));
 
-/// The main function reads lines from a file, converts each line to a coordinate, and calculates the sum of all
-/// coordinates.
-fn main() {
-    let path: String = "res/data.txt".to_string();
-    let lines = get_input(path);
-    let mut sum: u32 = 0;
-    for line in lines {
-        sum += get_coord(line);
-    }
-
-    println!("Sum is {}", sum);
-
-    // println!("{:?}", get_coord("two1nine".to_string()));
-    // println!("{:?}", get_coord("eightwothree".to_string()));
-    // println!("{:?}", get_coord("abcone2threexyz".to_string()));
-    // println!("{:?}", get_coord("xtwone3four".to_string()));
-    // println!("{:?}", get_coord("4nineeightseven2".to_string()));
-    // println!("{:?}", get_coord("zoneight234".to_string()));
-    // println!("{:?}", get_coord("7pqrstsixteen".to_string()));
+    ExitCode::SUCCESS
 }
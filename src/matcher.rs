@@ -0,0 +1,48 @@
+use std::sync::OnceLock;
+
+use aho_corasick::AhoCorasick;
+
+use crate::DIGITS;
+
+/// The numerals `0` through `9`, stored at the index equal to their numeric value, matched alongside the words of
+/// `DIGITS`.
+const NUMERALS: &[&str] = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// The function `automaton` returns the shared Aho-Corasick automaton over the spelled words of `DIGITS` followed by
+/// the numerals, built on first use.
+fn automaton() -> &'static AhoCorasick {
+    static AUTOMATON: OnceLock<AhoCorasick> = OnceLock::new();
+    AUTOMATON.get_or_init(|| {
+        AhoCorasick::new(DIGITS.iter().chain(NUMERALS)).expect("digit patterns are valid")
+    })
+}
+
+/// The function `first_and_last` finds the first and last digit of a line, spelled or numeric, in a single traversal
+/// of the precompiled automaton. Overlapping matches are all reported, so `"oneight"` yields both "one" and "eight".
+///
+/// Arguments:
+///
+/// * `line`: The `line` parameter is a string slice (`&str`) that represents a line of text.
+///
+/// Returns:
+///
+/// The function `first_and_last` returns an `Option<(u8, u8)>` holding the values of the digits starting first and
+/// last, or `None` when the line contains no digit.
+pub fn first_and_last(line: &str) -> Option<(u8, u8)> {
+    let mut first: Option<(usize, u8)> = None;
+    let mut last: Option<(usize, u8)> = None;
+
+    for found in automaton().find_overlapping_iter(line) {
+        let value = (found.pattern().as_usize() % DIGITS.len()) as u8;
+        let start = found.start();
+
+        if first.is_none_or(|(first_start, _)| start < first_start) {
+            first = Some((start, value));
+        }
+        if last.is_none_or(|(last_start, _)| start > last_start) {
+            last = Some((start, value));
+        }
+    }
+
+    Some((first?.1, last?.1))
+}